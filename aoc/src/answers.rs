@@ -0,0 +1,148 @@
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use common::{workspace_dir, Answer};
+use serde::{Deserialize, Serialize};
+
+/// Environment variable overriding the path of the answers file.
+pub const ANSWERS_VAR: &str = "AOC_ANSWERS";
+
+/// Returns path of the answers file, `answers.toml` in the workspace root by default.
+pub fn answers_path() -> PathBuf {
+    env::var_os(ANSWERS_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace_dir().join("answers.toml"))
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl PartAnswers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Answers known to be correct, keyed by day and input name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, PartAnswers>>,
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+impl Answers {
+    pub fn from_toml(contents: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    pub fn to_toml(&self) -> String {
+        toml::to_string_pretty(self).expect("Answers must be serializable")
+    }
+
+    /// Loads answers from a file, which is treated as empty if it doesn't exist.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::from_toml(&contents)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_toml())
+    }
+
+    /// Returns answers recorded for every input of a given day.
+    pub fn inputs(&self, day: u8) -> impl Iterator<Item = (&str, &PartAnswers)> {
+        self.days
+            .get(&day_key(day))
+            .into_iter()
+            .flatten()
+            .map(|(name, answers)| (name.as_str(), answers))
+    }
+
+    /// Adds an input without any answers, unless the input is already known.
+    pub fn register(&mut self, day: u8, name: &str) {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(name.to_string())
+            .or_default();
+    }
+
+    pub fn set(&mut self, day: u8, name: &str, part: u8, answer: &Answer) {
+        let answers = self
+            .days
+            .entry(day_key(day))
+            .or_default()
+            .entry(name.to_string())
+            .or_default();
+
+        let answer = Some(answer.to_string());
+        match part {
+            1 => answers.part1 = answer,
+            2 => answers.part2 = answer,
+            _ => panic!("Puzzle has only two parts"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"[day01.example]
+part1 = "11"
+part2 = "31"
+
+[day01.real]
+part1 = "1234"
+
+[day11.example]
+part1 = "55312"
+"#;
+
+    #[test]
+    fn answers_inputs() {
+        let answers = Answers::from_toml(ANSWERS).unwrap();
+
+        let day01 = answers.inputs(1).collect::<Vec<_>>();
+
+        assert_eq!(day01.len(), 2);
+        assert_eq!(day01[0].0, "example");
+        assert_eq!(day01[0].1.get(2), Some("31"));
+        assert_eq!(day01[1].0, "real");
+        assert_eq!(day01[1].1.get(2), None);
+        assert_eq!(answers.inputs(2).count(), 0);
+    }
+
+    #[test]
+    fn answers_set_round_trip() {
+        let mut answers = Answers::default();
+
+        answers.set(11, "example", 1, &Answer::from(55312u64));
+        answers.set(1, "real", 1, &Answer::from(1234u64));
+        answers.set(1, "example", 2, &Answer::from(31u64));
+        answers.set(1, "example", 1, &Answer::from(11u64));
+
+        assert_eq!(answers.to_toml(), ANSWERS);
+        assert_eq!(Answers::from_toml(&answers.to_toml()).unwrap(), answers);
+    }
+}
@@ -0,0 +1,338 @@
+use std::{
+    fs, io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use common::{ParseError, Solution};
+use serde::{Deserialize, Serialize};
+
+/// Median durations of each step of solving a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_1: Duration,
+    pub part_2: Duration,
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations[durations.len() / 2]
+}
+
+/// Solves the puzzle `iterations` times, timing parsing separately from both parts.
+pub fn measure<S: Solution>(input: &str, iterations: u32) -> Result<Timings, ParseError> {
+    assert!(iterations > 0, "At least one iteration is required");
+
+    let mut parse = Vec::with_capacity(iterations as usize);
+    let mut part_1 = Vec::with_capacity(iterations as usize);
+    let mut part_2 = Vec::with_capacity(iterations as usize);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = S::parse(std::hint::black_box(input))?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        std::hint::black_box(S::part_1(&parsed));
+        part_1.push(start.elapsed());
+
+        let start = Instant::now();
+        std::hint::black_box(S::part_2(&parsed));
+        part_2.push(start.elapsed());
+    }
+
+    Ok(Timings {
+        parse: median(parse),
+        part_1: median(part_1),
+        part_2: median(part_2),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    /// Name of the measured input, e.g. `example`.
+    pub input: String,
+    pub parse_ns: u64,
+    pub part_1_ns: u64,
+    pub part_2_ns: u64,
+}
+
+impl Measurement {
+    pub fn new(day: u8, input: &str, timings: Timings) -> Self {
+        Self {
+            day,
+            input: input.to_string(),
+            parse_ns: timings.parse.as_nanos() as u64,
+            part_1_ns: timings.part_1.as_nanos() as u64,
+            part_2_ns: timings.part_2.as_nanos() as u64,
+        }
+    }
+
+    fn steps(&self) -> [(&'static str, u64); 3] {
+        [
+            ("parse", self.parse_ns),
+            ("part 1", self.part_1_ns),
+            ("part 2", self.part_2_ns),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub measurements: Vec<Measurement>,
+}
+
+const CSV_HEADER: &str = "day,input,parse_ns,part_1_ns,part_2_ns";
+
+impl Report {
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{CSV_HEADER}\n");
+        for m in &self.measurements {
+            csv += &format!(
+                "{},{},{},{},{}\n",
+                m.day,
+                csv_field(&m.input),
+                m.parse_ns,
+                m.part_1_ns,
+                m.part_2_ns
+            );
+        }
+        csv
+    }
+
+    pub fn from_csv(csv: &str) -> Result<Self, String> {
+        let mut rows = csv_rows(csv)?.into_iter();
+        if rows.next().map(|header| header.join(",")).as_deref() != Some(CSV_HEADER) {
+            return Err(format!("Expected CSV header `{CSV_HEADER}`"));
+        }
+
+        let measurements = rows
+            .enumerate()
+            .map(|(idx, fields)| {
+                let invalid_row = || format!("Invalid CSV row {}: `{}`", idx + 2, fields.join(","));
+                let [day, input, parse_ns, part_1_ns, part_2_ns] = &fields[..] else {
+                    return Err(invalid_row());
+                };
+
+                Ok(Measurement {
+                    day: day.parse().map_err(|_| invalid_row())?,
+                    input: input.to_string(),
+                    parse_ns: parse_ns.parse().map_err(|_| invalid_row())?,
+                    part_1_ns: part_1_ns.parse().map_err(|_| invalid_row())?,
+                    part_2_ns: part_2_ns.parse().map_err(|_| invalid_row())?,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { measurements })
+    }
+
+    /// Loads report from a `.json` or `.csv` file.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let report = if is_csv(path) {
+            Self::from_csv(&contents).map_err(io::Error::other)?
+        } else {
+            serde_json::from_str(&contents)?
+        };
+        Ok(report)
+    }
+
+    /// Saves report to a file, as CSV if it has a `.csv` extension and as JSON otherwise.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents = if is_csv(path) {
+            self.to_csv()
+        } else {
+            serde_json::to_string_pretty(self)?
+        };
+        fs::write(path, contents)
+    }
+}
+
+/// Quotes CSV field if it contains a delimiter, a quote or a line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Splits CSV into rows of fields, unquoting quoted fields. Empty lines are skipped.
+fn csv_rows(csv: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut chars = csv.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if field.is_empty() => loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(char) => field.push(char),
+                    None => return Err("Unterminated quoted CSV field".to_string()),
+                }
+            },
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                if row != [""] {
+                    rows.push(row.clone());
+                }
+                row.clear();
+            }
+            char => field.push(char),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+fn is_csv(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension == "csv")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub day: u8,
+    pub input: String,
+    pub step: &'static str,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+}
+
+impl Regression {
+    /// Relative slowdown in percent, or `None` if the baseline was too fast to be measured.
+    pub fn slowdown(&self) -> Option<f64> {
+        (self.baseline_ns > 0)
+            .then(|| (self.current_ns as f64 / self.baseline_ns as f64 - 1.0) * 100.0)
+    }
+}
+
+/// Finds steps which got slower than in `baseline` by more than `threshold` percent.
+pub fn find_regressions(baseline: &Report, current: &Report, threshold: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+
+    for current in &current.measurements {
+        let Some(baseline) = baseline
+            .measurements
+            .iter()
+            .find(|m| m.day == current.day && m.input == current.input)
+        else {
+            continue;
+        };
+
+        for ((step, baseline_ns), (_, current_ns)) in
+            baseline.steps().into_iter().zip(current.steps())
+        {
+            if current_ns as f64 > baseline_ns as f64 * (1.0 + threshold / 100.0) {
+                regressions.push(Regression {
+                    day: current.day,
+                    input: current.input.clone(),
+                    step,
+                    baseline_ns,
+                    current_ns,
+                });
+            }
+        }
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(day: u8, parse_ns: u64, part_1_ns: u64, part_2_ns: u64) -> Measurement {
+        Measurement {
+            day,
+            input: "example".to_string(),
+            parse_ns,
+            part_1_ns,
+            part_2_ns,
+        }
+    }
+
+    #[test]
+    fn report_csv_round_trip() {
+        let report = Report {
+            measurements: vec![measurement(1, 10, 20, 30), measurement(2, 40, 50, 60)],
+        };
+
+        let csv = report.to_csv();
+
+        assert_eq!(
+            csv,
+            "day,input,parse_ns,part_1_ns,part_2_ns\n1,example,10,20,30\n2,example,40,50,60\n"
+        );
+        assert_eq!(Report::from_csv(&csv).unwrap(), report);
+    }
+
+    #[test]
+    fn report_invalid_csv() {
+        let csv = "day,input,parse_ns,part_1_ns,part_2_ns\n1,example,10,20";
+
+        assert_eq!(
+            Report::from_csv(csv).unwrap_err(),
+            "Invalid CSV row 2: `1,example,10,20`"
+        );
+    }
+
+    #[test]
+    fn regressions_above_threshold() {
+        let baseline = Report {
+            measurements: vec![measurement(1, 100, 100, 100), measurement(2, 100, 100, 100)],
+        };
+        let current = Report {
+            measurements: vec![measurement(1, 105, 130, 90), measurement(3, 500, 500, 500)],
+        };
+
+        let regressions = find_regressions(&baseline, &current, 10.0);
+
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].day, 1);
+        assert_eq!(regressions[0].step, "part 1");
+        assert_eq!(regressions[0].slowdown().map(f64::round), Some(30.0));
+    }
+
+    #[test]
+    fn regression_from_zero_baseline() {
+        let baseline = Report {
+            measurements: vec![measurement(1, 0, 100, 100)],
+        };
+        let current = Report {
+            measurements: vec![measurement(1, 50, 100, 100)],
+        };
+
+        let regressions = find_regressions(&baseline, &current, 10.0);
+
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].slowdown(), None);
+    }
+
+    #[test]
+    fn report_csv_quotes_input_names() {
+        let mut quoted = measurement(1, 10, 20, 30);
+        quoted.input = "big, \"slow\"".to_string();
+        let report = Report {
+            measurements: vec![quoted, measurement(2, 40, 50, 60)],
+        };
+
+        let csv = report.to_csv();
+
+        assert!(csv.contains("\n1,\"big, \"\"slow\"\"\",10,20,30\n"));
+        assert_eq!(Report::from_csv(&csv).unwrap(), report);
+    }
+}
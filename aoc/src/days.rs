@@ -0,0 +1,63 @@
+use common::{Answer, ParseError, Solution};
+
+use crate::bench::{self, Timings};
+
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    pub example: &'static str,
+    pub solve_part_1: fn(&str) -> Result<Answer, ParseError>,
+    pub solve_part_2: fn(&str) -> Result<Answer, ParseError>,
+    pub measure: fn(&str, u32) -> Result<Timings, ParseError>,
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            title: S::TITLE,
+            example: S::EXAMPLE,
+            solve_part_1: common::solve_part_1::<S>,
+            solve_part_2: common::solve_part_2::<S>,
+            measure: bench::measure::<S>,
+        }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day02::Day02>(),
+    Day::new::<day03::Day03>(),
+    Day::new::<day04::Day04>(),
+    Day::new::<day05::Day05>(),
+    Day::new::<day06::Day06>(),
+    Day::new::<day07::Day07>(),
+    Day::new::<day08::Day08>(),
+    Day::new::<day09::Day09>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_unique_and_ordered() {
+        assert!(DAYS.windows(2).all(|days| days[0].number < days[1].number));
+    }
+
+    #[test]
+    fn find_day_dispatches_to_crate() {
+        let day = find_day(11).unwrap();
+
+        assert_eq!(day.number, 11);
+        assert_eq!((day.solve_part_1)("125 17"), Ok(Answer::from(55312u64)));
+        assert_eq!(day.title, "Plutonian Pebbles");
+        assert!(find_day(25).is_none());
+    }
+}
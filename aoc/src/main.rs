@@ -0,0 +1,379 @@
+mod answers;
+mod bench;
+mod days;
+mod scaffold;
+
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use answers::{answers_path, Answers};
+use bench::{find_regressions, Measurement, Report};
+use clap::{Parser, Subcommand};
+use common::{
+    input::{InputStore, EXAMPLE, REAL},
+    workspace_dir, Answer, ParseError,
+};
+use days::{find_day, Day, DAYS};
+
+#[derive(Parser)]
+#[command(about = "Runs Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves the puzzle of a given day.
+    Run {
+        /// Day of the puzzle.
+        day: u8,
+
+        /// Solve only the given part of the puzzle.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Name of the cached puzzle input.
+        #[arg(short, long, default_value = REAL)]
+        name: String,
+
+        /// Path to the puzzle input, `-` reads from stdin. Overrides `--name`.
+        #[arg(short, long, conflicts_with = "record")]
+        input: Option<PathBuf>,
+
+        /// Record the answers as correct for the named input.
+        #[arg(short, long)]
+        record: bool,
+    },
+    /// Measures parsing and solving times of given days, or all days if none are given.
+    Bench {
+        days: Vec<u8>,
+
+        /// Name of the cached inputs to measure the solutions on.
+        #[arg(short = 'N', long, default_value = EXAMPLE)]
+        name: String,
+
+        /// Number of runs, of which the median time is reported.
+        #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        /// Path to save the report to, as CSV if it ends with `.csv` and as JSON otherwise.
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Path to a previously saved report to compare against.
+        #[arg(short, long)]
+        baseline: Option<PathBuf>,
+
+        /// Slowdown in percent above which a step is reported as regressed.
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Solves given days, or all days if none are given, and compares with recorded answers.
+    Verify { days: Vec<u8> },
+    /// Creates crate for a new day from the template and registers it with the runner.
+    New {
+        /// Day of the puzzle.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+/// Loads input from the store, falling back to the embedded example if it isn't cached.
+fn load_input(store: &InputStore, day: &Day, name: &str) -> io::Result<String> {
+    match store.load(day.number, name) {
+        Err(err) if err.kind() == io::ErrorKind::NotFound && name == EXAMPLE => {
+            Ok(day.example.to_string())
+        }
+        result => result,
+    }
+}
+
+/// Finds days with given numbers, or returns all days if no numbers are given.
+fn select_days(numbers: Vec<u8>) -> Option<Vec<&'static Day>> {
+    if numbers.is_empty() {
+        return Some(DAYS.iter().collect());
+    }
+
+    numbers
+        .into_iter()
+        .map(|number| {
+            let day = find_day(number);
+            if day.is_none() {
+                eprintln!("Day {number} is not solved yet");
+            }
+            day
+        })
+        .collect()
+}
+
+fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        return Ok(contents);
+    }
+
+    fs::read_to_string(path)
+}
+
+fn solve(day: &Day, part: Option<u8>, input: &str) -> Result<Vec<(u8, Answer)>, ParseError> {
+    println!("Day {}: {}", day.number, day.title);
+
+    let mut answers = Vec::new();
+    for (number, solve_part) in [(1, day.solve_part_1), (2, day.solve_part_2)] {
+        if part.is_some_and(|part| part != number) {
+            continue;
+        }
+
+        let answer = solve_part(input)?;
+        println!("Part {number}: {answer}");
+        answers.push((number, answer));
+    }
+
+    Ok(answers)
+}
+
+fn run(
+    store: &InputStore,
+    day: u8,
+    part: Option<u8>,
+    name: &str,
+    input: Option<PathBuf>,
+    record: bool,
+) -> ExitCode {
+    let Some(day) = find_day(day) else {
+        eprintln!("Day {day} is not solved yet");
+        return ExitCode::FAILURE;
+    };
+
+    let contents = match &input {
+        Some(path) => read_input(path),
+        None => load_input(store, day, name),
+    };
+    let contents = match contents {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("Failed to read puzzle input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solved = match solve(day, part, &contents) {
+        Ok(solved) => solved,
+        Err(err) => {
+            eprintln!("Failed to parse puzzle input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if record {
+        let path = answers_path();
+        let result = Answers::load(&path).and_then(|mut answers| {
+            for (part, answer) in &solved {
+                answers.set(day.number, name, *part, answer);
+            }
+            answers.save(&path)
+        });
+
+        if let Err(err) = result {
+            eprintln!("Failed to record answers in {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn bench(
+    store: &InputStore,
+    days: Vec<u8>,
+    name: &str,
+    iterations: u32,
+    output: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+) -> ExitCode {
+    let Some(selected_days) = select_days(days) else {
+        return ExitCode::FAILURE;
+    };
+
+    let mut report = Report::default();
+    println!(
+        "Day  Input     {:>12}  {:>12}  {:>12}",
+        "Parse", "Part 1", "Part 2"
+    );
+
+    for day in selected_days {
+        let input = match load_input(store, day, name) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Skipping day {}: {err}", day.number);
+                continue;
+            }
+        };
+
+        let timings = match (day.measure)(&input, iterations) {
+            Ok(timings) => timings,
+            Err(err) => {
+                eprintln!("Failed to parse input of day {}: {err}", day.number);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let measurement = Measurement::new(day.number, name, timings);
+        println!(
+            "{:>3}  {:<8}  {:>12?}  {:>12?}  {:>12?}",
+            measurement.day,
+            measurement.input,
+            Duration::from_nanos(measurement.parse_ns),
+            Duration::from_nanos(measurement.part_1_ns),
+            Duration::from_nanos(measurement.part_2_ns),
+        );
+        report.measurements.push(measurement);
+    }
+
+    if let Some(output) = output {
+        if let Err(err) = report.save(&output) {
+            eprintln!("Failed to save report to {}: {err}", output.display());
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let Some(baseline) = baseline else {
+        return ExitCode::SUCCESS;
+    };
+
+    let baseline = match Report::load(&baseline) {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("Failed to load baseline from {}: {err}", baseline.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let regressions = find_regressions(&baseline, &report, threshold);
+    for regression in &regressions {
+        let slowdown = match regression.slowdown() {
+            Some(slowdown) => format!(" (+{slowdown:.1}%)"),
+            None => String::new(),
+        };
+        println!(
+            "Regression in day {} ({}) {}: {:?} -> {:?}{slowdown}",
+            regression.day,
+            regression.input,
+            regression.step,
+            Duration::from_nanos(regression.baseline_ns),
+            Duration::from_nanos(regression.current_ns),
+        );
+    }
+
+    if regressions.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn verify(store: &InputStore, days: Vec<u8>) -> ExitCode {
+    let Some(days) = select_days(days) else {
+        return ExitCode::FAILURE;
+    };
+
+    let answers = match Answers::load(&answers_path()) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("Failed to load answers: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut num_failures = 0;
+    for day in days {
+        for (name, expected) in answers.inputs(day.number) {
+            let input = match load_input(store, day, name) {
+                Ok(input) => input,
+                Err(err) => {
+                    println!("Day {} ({name}): skipped, {err}", day.number);
+                    continue;
+                }
+            };
+
+            for (part, solve_part) in [(1, day.solve_part_1), (2, day.solve_part_2)] {
+                let Some(expected) = expected.get(part) else {
+                    continue;
+                };
+
+                let prefix = format!("Day {} ({name}) part {part}", day.number);
+                match solve_part(&input) {
+                    Ok(answer) if answer.to_string() == expected => println!("{prefix}: ok"),
+                    Ok(answer) => {
+                        println!("{prefix}: MISMATCH, expected {expected}, got {answer}");
+                        num_failures += 1;
+                    }
+                    Err(err) => {
+                        println!("{prefix}: FAILED to parse input, {err}");
+                        num_failures += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    if num_failures > 0 {
+        eprintln!("{num_failures} answer(s) don't match");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn new(day: u8) -> ExitCode {
+    match scaffold::create_day(workspace_dir(), &answers_path(), day) {
+        Ok(crate_dir) => {
+            println!("Created {}", crate_dir.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Failed to create day {day}: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let store = match InputStore::from_env() {
+        Ok(store) => store,
+        Err(err) => {
+            eprintln!("Failed to configure input store: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            name,
+            input,
+            record,
+        } => run(&store, day, part, &name, input, record),
+        Command::Bench {
+            days,
+            name,
+            iterations,
+            output,
+            baseline,
+            threshold,
+        } => bench(&store, days, &name, iterations, output, baseline, threshold),
+        Command::Verify { days } => verify(&store, days),
+        Command::New { day } => new(day),
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::answers::Answers;
+use common::input::EXAMPLE;
+
+/// Files of a day crate, relative to its directory, embedded from the `template` directory.
+const TEMPLATE: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../../template/Cargo.toml")),
+    ("src/lib.rs", include_str!("../../template/src/lib.rs")),
+    ("src/main.rs", include_str!("../../template/src/main.rs")),
+    ("src/parse.rs", include_str!("../../template/src/parse.rs")),
+];
+
+/// Fills in template placeholders for a given day.
+fn render(template: &str, day: u8) -> String {
+    let crate_name = format!("day{day:02}");
+    let type_name = format!("Day{day:02}");
+
+    template
+        .replace("{{project-name | upper_camel_case}}", &type_name)
+        .replace("{{project-name}}", &crate_name)
+        .replace("{{crate_name}}", &crate_name)
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Inserts `line` among the lines starting with `prefix`, keeping them sorted.
+///
+/// Returns `None` if there are no such lines, as there's no telling where to put it.
+fn insert_sorted(contents: &str, prefix: &str, line: &str) -> Option<String> {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, existing)| existing.trim_start().starts_with(prefix))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    if entries.iter().any(|&idx| lines[idx].trim() == line.trim()) {
+        return Some(contents.to_string());
+    }
+
+    let idx = entries
+        .iter()
+        .copied()
+        .find(|&idx| lines[idx].trim() > line.trim())
+        .or_else(|| entries.last().map(|&idx| idx + 1))?;
+    lines.insert(idx, line);
+
+    let mut contents_with_line = lines.join("\n");
+    if contents.ends_with('\n') {
+        contents_with_line.push('\n');
+    }
+
+    Some(contents_with_line)
+}
+
+fn register(path: &Path, prefix: &str, line: &str) -> io::Result<String> {
+    let contents = fs::read_to_string(path)?;
+    insert_sorted(&contents, prefix, line).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "No `{prefix}` entries to register day next to in {}",
+                path.display()
+            ),
+        )
+    })
+}
+
+/// Creates crate of a new day from the template, adds it to the runner and registers its
+/// example input in the answers file. Returns directory of the created crate.
+pub fn create_day(workspace: &Path, answers_path: &Path, day: u8) -> io::Result<PathBuf> {
+    let crate_name = format!("day{day:02}");
+    let crate_dir = workspace.join(&crate_name);
+    if crate_dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("Crate {} already exists", crate_dir.display()),
+        ));
+    }
+
+    // Prepare all edits upfront, so nothing is created if any of them fails.
+    let manifest_path = workspace.join("aoc/Cargo.toml");
+    let manifest = register(
+        &manifest_path,
+        "day",
+        &format!("{crate_name} = {{ path = \"../{crate_name}\" }}"),
+    )?;
+    let days_path = workspace.join("aoc/src/days.rs");
+    let days = register(
+        &days_path,
+        "Day::new::<",
+        &format!("    Day::new::<{crate_name}::Day{day:02}>(),"),
+    )?;
+    let mut answers = Answers::load(answers_path)?;
+    answers.register(day, EXAMPLE);
+
+    for (file, template) in TEMPLATE {
+        let path = crate_dir.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, render(template, day))?;
+    }
+
+    fs::write(manifest_path, manifest)?;
+    fs::write(days_path, days)?;
+    answers.save(answers_path)?;
+
+    Ok(crate_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    const MANIFEST: &str = r#"[dependencies]
+common.workspace = true
+day01 = { path = "../day01" }
+day03 = { path = "../day03" }
+serde.workspace = true
+"#;
+
+    const DAYS: &str = "pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(),
+    Day::new::<day03::Day03>(),
+];
+";
+
+    #[test]
+    fn render_fills_in_placeholders() {
+        for (file, template) in TEMPLATE {
+            let rendered = render(template, 7);
+
+            assert!(!rendered.contains("{{"), "Placeholder left in {file}");
+        }
+
+        let lib = render(TEMPLATE[1].1, 7);
+        assert!(lib.contains("impl Solution for Day07 {"));
+        assert!(lib.contains("const DAY: u8 = 7;"));
+        assert!(!lib.contains("todo!()"));
+        let main = render(TEMPLATE[2].1, 7);
+        assert!(main.contains("use day07::*;"));
+        assert_eq!(main.matches("#[test]\n    #[ignore").count(), 2);
+    }
+
+    #[test]
+    fn insert_sorted_keeps_order() {
+        let manifest = insert_sorted(MANIFEST, "day", r#"day02 = { path = "../day02" }"#).unwrap();
+        let manifest = insert_sorted(&manifest, "day", r#"day12 = { path = "../day12" }"#).unwrap();
+
+        assert_eq!(
+            manifest,
+            r#"[dependencies]
+common.workspace = true
+day01 = { path = "../day01" }
+day02 = { path = "../day02" }
+day03 = { path = "../day03" }
+day12 = { path = "../day12" }
+serde.workspace = true
+"#
+        );
+        assert_eq!(
+            insert_sorted(&manifest, "day", r#"day02 = { path = "../day02" }"#).unwrap(),
+            manifest
+        );
+        assert_eq!(insert_sorted(MANIFEST, "Day::new::<", "day02"), None);
+    }
+
+    #[test]
+    fn create_day_registers_crate() {
+        let workspace = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&workspace);
+        fs::create_dir_all(workspace.join("aoc/src")).unwrap();
+        fs::write(workspace.join("aoc/Cargo.toml"), MANIFEST).unwrap();
+        fs::write(workspace.join("aoc/src/days.rs"), DAYS).unwrap();
+        let answers_path = workspace.join("answers.toml");
+
+        let crate_dir = create_day(&workspace, &answers_path, 2).unwrap();
+
+        assert_eq!(crate_dir, workspace.join("day02"));
+        assert!(crate_dir.join("src/parse.rs").exists());
+        assert!(fs::read_to_string(crate_dir.join("Cargo.toml"))
+            .unwrap()
+            .contains(r#"name = "day02""#));
+        assert!(fs::read_to_string(workspace.join("aoc/src/days.rs"))
+            .unwrap()
+            .contains("Day::new::<day01::Day01>(),\n    Day::new::<day02::Day02>(),\n"));
+        assert_eq!(Answers::load(&answers_path).unwrap().inputs(2).count(), 1);
+
+        let error = create_day(&workspace, &answers_path, 2).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+
+        fs::remove_dir_all(workspace).unwrap();
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt::{Display, Formatter};
+
+/// Answer to a part of a puzzle, independent of the integer type used by the solver.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+}
+
+macro_rules! impl_from_unsigned {
+    ($($type:ty),*) => {
+        $(impl From<$type> for Answer {
+            fn from(value: $type) -> Self {
+                Answer::Unsigned(value.into())
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($type:ty),*) => {
+        $(impl From<$type> for Answer {
+            fn from(value: $type) -> Self {
+                Answer::Signed(value.into())
+            }
+        })*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128);
+impl_from_signed!(i8, i16, i32, i64, i128);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_from_integers() {
+        assert_eq!(Answer::from(42u64), Answer::Unsigned(42));
+        assert_eq!(Answer::from(42usize), Answer::Unsigned(42));
+        assert_eq!(Answer::from(-42i64), Answer::Signed(-42));
+        assert_eq!(Answer::from(u128::MAX), Answer::Unsigned(u128::MAX));
+    }
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::from(123u32).to_string(), "123");
+        assert_eq!(Answer::from(-7i8).to_string(), "-7");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+}
@@ -0,0 +1,339 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::workspace_dir;
+
+/// Environment variable overriding the cache directory.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+/// Environment variable overriding the path of the config file.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Name of the personal puzzle input.
+pub const REAL: &str = "real";
+/// Name of the example input from the puzzle description.
+pub const EXAMPLE: &str = "example";
+
+/// Source of puzzle inputs missing from the cache.
+pub trait Fetcher {
+    fn fetch(&self, day: u8, name: &str) -> io::Result<String>;
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Config {
+    #[serde(default)]
+    inputs: InputsConfig,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct InputsConfig {
+    cache_dir: Option<PathBuf>,
+}
+
+/// Cache of puzzle inputs, stored as `{cache_dir}/dayNN/{name}.txt`.
+///
+/// Each day can have several named inputs, e.g. [`REAL`], [`EXAMPLE`] or `stress`.
+pub struct InputStore {
+    cache_dir: PathBuf,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl InputStore {
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+            fetcher: None,
+        }
+    }
+
+    pub fn with_fetcher(mut self, fetcher: impl Fetcher + 'static) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    /// Creates store configured by environment variables, falling back to the `aoc.toml`
+    /// config file and then to the `inputs` directory in the workspace root.
+    pub fn from_env() -> io::Result<Self> {
+        let config_path = env::var_os(CONFIG_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| workspace_dir().join("aoc.toml"));
+
+        let config: Config = match fs::read_to_string(&config_path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(err) => return Err(err),
+        };
+
+        // Relative paths in the config file are relative to the file itself.
+        let config_dir = config_path.parent().unwrap_or(Path::new(""));
+        let cache_dir = env::var_os(CACHE_DIR_VAR)
+            .map(PathBuf::from)
+            .or_else(|| config.inputs.cache_dir.map(|dir| config_dir.join(dir)))
+            .unwrap_or_else(|| workspace_dir().join("inputs"));
+
+        Ok(Self::new(cache_dir))
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// Returns path of a named input, rejecting names which would point outside of the cache.
+    pub fn path(&self, day: u8, name: &str) -> io::Result<PathBuf> {
+        if name.is_empty() || name.contains(['/', '\\']) || name.contains("..") {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid input name `{name}`"),
+            ));
+        }
+
+        Ok(self
+            .cache_dir
+            .join(format!("day{day:02}"))
+            .join(format!("{name}.txt")))
+    }
+
+    /// Returns names of all cached inputs of a given day, sorted alphabetically.
+    pub fn names(&self, day: u8) -> io::Result<Vec<String>> {
+        let day_dir = self.cache_dir.join(format!("day{day:02}"));
+        let entries = match fs::read_dir(day_dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+
+        let mut names = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == "txt") {
+                if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                    names.push(name.to_string());
+                }
+            }
+        }
+        names.sort();
+
+        Ok(names)
+    }
+
+    pub fn store(&self, day: u8, name: &str, contents: &str) -> io::Result<()> {
+        let path = self.path(day, name)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)
+    }
+
+    /// Reads cached input, fetching and caching it first if it's missing.
+    pub fn load(&self, day: u8, name: &str) -> io::Result<String> {
+        let path = self.path(day, name)?;
+        match fs::read_to_string(&path) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            result => return result,
+        }
+
+        let Some(fetcher) = &self.fetcher else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "Input `{name}` of day {day} not found at {}",
+                    path.display()
+                ),
+            ));
+        };
+
+        let contents = fetcher.fetch(day, name)?;
+        self.store(day, name, &contents)?;
+
+        Ok(contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        thread,
+    };
+
+    use super::*;
+
+    /// Stand-in fetcher, requesting inputs over plain HTTP from `{base_url}/day/{day}/{name}`.
+    struct HttpFetcher {
+        host: String,
+        path_prefix: String,
+    }
+
+    impl HttpFetcher {
+        fn new(base_url: &str) -> io::Result<Self> {
+            let invalid_url = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "Unsupported fetch URL `{base_url}`, expected `http://host[:port][/path]`"
+                    ),
+                )
+            };
+
+            let url = base_url.strip_prefix("http://").ok_or_else(invalid_url)?;
+            let (host, path_prefix) = url.split_once('/').unwrap_or((url, ""));
+            if host.is_empty() {
+                return Err(invalid_url());
+            }
+
+            Ok(Self {
+                host: host.to_string(),
+                path_prefix: path_prefix.trim_end_matches('/').to_string(),
+            })
+        }
+    }
+
+    impl Fetcher for HttpFetcher {
+        fn fetch(&self, day: u8, name: &str) -> io::Result<String> {
+            let address = if self.host.contains(':') {
+                self.host.clone()
+            } else {
+                format!("{}:80", self.host)
+            };
+
+            let mut path = String::new();
+            if !self.path_prefix.is_empty() {
+                path = format!("/{}", self.path_prefix);
+            }
+            path += &format!("/day/{day}/{name}");
+
+            let mut stream = TcpStream::connect(address)?;
+            write!(
+                stream,
+                "GET {path} HTTP/1.0\r\nHost: {}\r\nConnection: close\r\n\r\n",
+                self.host
+            )?;
+
+            let mut response = String::new();
+            stream.read_to_string(&mut response)?;
+
+            let (head, body) = response.split_once("\r\n\r\n").ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "Malformed HTTP response")
+            })?;
+            let status = head.lines().next().unwrap_or_default();
+            if status.split(' ').nth(1) != Some("200") {
+                return Err(io::Error::other(format!(
+                    "Failed to fetch {path}: `{status}`"
+                )));
+            }
+
+            Ok(body.to_string())
+        }
+    }
+
+    fn temp_cache_dir(test: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{test}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Serves a single HTTP request, replying with `status` and `body`.
+    fn serve_once(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/inputs", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            // The request must be read whole, closing with unread data resets the connection.
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let len = stream.read(&mut buffer).unwrap();
+                if len == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..len]);
+            }
+            write!(
+                stream,
+                "HTTP/1.0 {status}\r\nContent-Type: text/plain\r\n\r\n{body}"
+            )
+            .unwrap();
+            String::from_utf8_lossy(&request).into_owned()
+        });
+
+        (url, server)
+    }
+
+    #[test]
+    fn store_and_load() {
+        let store = InputStore::new(temp_cache_dir("store"));
+
+        store.store(7, "stress", "1: 2 3").unwrap();
+        store.store(7, EXAMPLE, "4: 5 6").unwrap();
+
+        assert_eq!(store.load(7, "stress").unwrap(), "1: 2 3");
+        assert_eq!(store.names(7).unwrap(), [EXAMPLE, "stress"]);
+        assert!(store.names(8).unwrap().is_empty());
+        assert_eq!(
+            store.load(7, REAL).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+
+        fs::remove_dir_all(store.cache_dir()).unwrap();
+    }
+
+    #[test]
+    fn store_rejects_names_outside_cache() {
+        let store = InputStore::new(temp_cache_dir("names"));
+
+        for name in ["", "../../etc/x", "a/b", "a\\b", ".."] {
+            let error = store.store(7, name, "1").unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "name `{name}`");
+            assert!(store.load(7, name).is_err());
+        }
+        assert!(!store.cache_dir().exists());
+    }
+
+    #[test]
+    fn load_fetches_missing_input() {
+        let (url, server) = serve_once("200 OK", "125 17");
+        let store =
+            InputStore::new(temp_cache_dir("fetch")).with_fetcher(HttpFetcher::new(&url).unwrap());
+
+        assert_eq!(store.load(11, REAL).unwrap(), "125 17");
+        assert!(server
+            .join()
+            .unwrap()
+            .starts_with("GET /inputs/day/11/real HTTP/1.0"));
+
+        // Second load must be served from the cache, as the server is gone.
+        assert_eq!(store.load(11, REAL).unwrap(), "125 17");
+        assert_eq!(
+            fs::read_to_string(store.path(11, REAL).unwrap()).unwrap(),
+            "125 17"
+        );
+
+        fs::remove_dir_all(store.cache_dir()).unwrap();
+    }
+
+    #[test]
+    fn fetch_reports_http_errors() {
+        let (url, server) = serve_once("404 Not Found", "");
+        let fetcher = HttpFetcher::new(&url).unwrap();
+
+        let error = fetcher.fetch(3, REAL).unwrap_err();
+        server.join().unwrap();
+
+        assert!(error.to_string().contains("404 Not Found"));
+    }
+
+    #[test]
+    fn fetcher_rejects_unsupported_url() {
+        assert!(HttpFetcher::new("https://adventofcode.com").is_err());
+        assert!(HttpFetcher::new("http://").is_err());
+    }
+}
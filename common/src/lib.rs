@@ -0,0 +1,44 @@
+mod answer;
+pub mod input;
+pub mod parse;
+
+use std::path::Path;
+
+pub use answer::Answer;
+pub use parse::{ParseError, ReadError};
+
+/// Returns root directory of the workspace this crate was built in.
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("Crate must be a workspace member")
+}
+
+/// Puzzle of a single day, split into parsing and solving steps.
+pub trait Solution {
+    /// Day of the puzzle, starting at 1.
+    const DAY: u8;
+    /// Title of the puzzle.
+    const TITLE: &'static str;
+    /// Example input given in the puzzle description.
+    const EXAMPLE: &'static str;
+
+    /// Parsed puzzle input, shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_1(input: &Self::Input) -> Answer;
+
+    fn part_2(input: &Self::Input) -> Answer;
+}
+
+/// Parses the input and solves the first part of the puzzle.
+pub fn solve_part_1<S: Solution>(input: &str) -> Result<Answer, ParseError> {
+    Ok(S::part_1(&S::parse(input)?))
+}
+
+/// Parses the input and solves the second part of the puzzle.
+pub fn solve_part_2<S: Solution>(input: &str) -> Result<Answer, ParseError> {
+    Ok(S::part_2(&S::parse(input)?))
+}
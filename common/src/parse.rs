@@ -0,0 +1,166 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    io,
+};
+
+use nom::{
+    character::complete::multispace0,
+    combinator::all_consuming,
+    error::{Error as NomError, ErrorKind},
+    sequence::terminated,
+    Finish, IResult,
+};
+
+/// Error encountered while parsing puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column number in characters, starting at 1.
+    pub column: usize,
+    /// Offending text, up to the end of the line.
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates error pointing at byte `offset` within `input`.
+    pub fn at_offset(input: &str, offset: usize, reason: impl Into<String>) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+        let text = input[offset..].lines().next().unwrap_or_default();
+
+        Self::new(line, column, text, reason)
+    }
+
+    /// Converts error returned by `nom` parser, which was run on `input`.
+    pub fn from_nom(input: &str, error: NomError<&str>) -> Self {
+        let offset = input.len() - error.input.len();
+        let reason = match error.code {
+            _ if error.input.is_empty() => "unexpected end of input".to_string(),
+            ErrorKind::Eof => "unexpected input".to_string(),
+            code => format!("expected {}", code.description().to_lowercase()),
+        };
+
+        Self::at_offset(input, offset, reason)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.reason, self.line, self.column
+        )?;
+        if !self.text.is_empty() {
+            write!(f, ": `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Error encountered while reading puzzle input from a stream.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "failed to read input: {err}"),
+            ReadError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io(err) => Some(err),
+            ReadError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(err: ParseError) -> Self {
+        ReadError::Parse(err)
+    }
+}
+
+/// Runs `parser` over the whole `input`, allowing only trailing whitespace to remain.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> Result<T, ParseError> {
+    all_consuming(terminated(parser, multispace0))(input)
+        .finish()
+        .map(|(_, output)| output)
+        .map_err(|error| ParseError::from_nom(input, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{character::complete::*, multi::*};
+
+    use super::*;
+
+    #[test]
+    fn error_at_offset() {
+        let input = "12 34\n56 x8\n";
+
+        let error = ParseError::at_offset(input, 9, "invalid number");
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 4);
+        assert_eq!(error.text, "x8");
+        assert_eq!(
+            error.to_string(),
+            "invalid number at line 2, column 4: `x8`"
+        );
+    }
+
+    #[test]
+    fn parse_all_allows_trailing_whitespace() {
+        let numbers = parse_all("1 2 3\n", separated_list1(space1, digit1)).unwrap();
+
+        assert_eq!(numbers, vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn parse_all_rejects_leftover_input() {
+        let error = parse_all("1 2\n3 ?", separated_list1(multispace1, digit1)).unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 3);
+        assert_eq!(error.text, "?");
+    }
+}
@@ -0,0 +1,275 @@
+use std::{fmt, sync::Arc};
+
+use itertools::Itertools;
+
+/// How far apart two locations are.
+#[derive(Clone, Default)]
+pub enum Metric {
+    /// Absolute difference, as in the puzzle.
+    #[default]
+    Absolute,
+    /// Squared difference, saturating at `u64::MAX`.
+    Squared,
+    /// Distance returned by the function.
+    Custom(Arc<dyn Fn(u64, u64) -> u64 + Send + Sync>),
+}
+
+impl Metric {
+    pub fn custom(distance: impl Fn(u64, u64) -> u64 + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(distance))
+    }
+
+    pub fn distance(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Self::Absolute => a.abs_diff(b),
+            Self::Squared => a.abs_diff(b).saturating_pow(2),
+            Self::Custom(distance) => distance(a, b),
+        }
+    }
+}
+
+impl fmt::Debug for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Absolute => write!(f, "Absolute"),
+            Self::Squared => write!(f, "Squared"),
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// How locations of the two lists are paired up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Pairing {
+    /// Smallest with smallest, second smallest with second smallest and so on, as in the puzzle.
+    #[default]
+    Sorted,
+    /// Pairs minimizing the total distance, for any metric.
+    Optimal,
+    /// Locations at the same index.
+    Positional,
+}
+
+/// Total distance between two lists, together with the pairs it was computed from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListDistance {
+    pub total: u64,
+    pub pairs: Vec<(u64, u64)>,
+}
+
+/// Pairs up locations of two lists of equal length and sums distances of all pairs.
+pub fn list_distance(
+    list1: &[u64],
+    list2: &[u64],
+    pairing: Pairing,
+    metric: &Metric,
+) -> ListDistance {
+    assert_eq!(list1.len(), list2.len());
+
+    let pairs: Vec<_> = match pairing {
+        Pairing::Sorted => list1
+            .iter()
+            .copied()
+            .sorted()
+            .zip(list2.iter().copied().sorted())
+            .collect(),
+        Pairing::Positional => list1.iter().copied().zip(list2.iter().copied()).collect(),
+        Pairing::Optimal => {
+            let costs = list1
+                .iter()
+                .map(|&a| list2.iter().map(|&b| metric.distance(a, b)).collect())
+                .collect_vec();
+            optimal_assignment(&costs)
+                .into_iter()
+                .enumerate()
+                .map(|(i, j)| (list1[i], list2[j]))
+                .collect()
+        }
+    };
+
+    let total = pairs
+        .iter()
+        .map(|&(a, b)| metric.distance(a, b))
+        .fold(0, u64::saturating_add);
+
+    ListDistance { total, pairs }
+}
+
+/// Solves the assignment problem for a square cost matrix with the Hungarian algorithm, in
+/// O(n³). Returns column assigned to each row.
+fn optimal_assignment(costs: &[Vec<u64>]) -> Vec<usize> {
+    let n = costs.len();
+
+    // Potentials of rows and columns, and row matched to each column, all indexed from 1, with
+    // column 0 being a virtual one holding the row currently being added.
+    let mut row_potentials = vec![0i128; n + 1];
+    let mut column_potentials = vec![0i128; n + 1];
+    let mut matched_rows = vec![0usize; n + 1];
+    let mut previous_columns = vec![0usize; n + 1];
+
+    for row in 1..=n {
+        matched_rows[0] = row;
+        let mut column = 0;
+        let mut min_slacks = vec![i128::MAX; n + 1];
+        let mut used = vec![false; n + 1];
+
+        // Grow alternating path from the new row until it reaches an unmatched column.
+        loop {
+            used[column] = true;
+            let current_row = matched_rows[column];
+            let mut delta = i128::MAX;
+            let mut next_column = 0;
+
+            for j in 1..=n {
+                if used[j] {
+                    continue;
+                }
+
+                let slack = costs[current_row - 1][j - 1] as i128
+                    - row_potentials[current_row]
+                    - column_potentials[j];
+                if slack < min_slacks[j] {
+                    min_slacks[j] = slack;
+                    previous_columns[j] = column;
+                }
+                if min_slacks[j] < delta {
+                    delta = min_slacks[j];
+                    next_column = j;
+                }
+            }
+
+            for j in 0..=n {
+                if used[j] {
+                    row_potentials[matched_rows[j]] += delta;
+                    column_potentials[j] -= delta;
+                } else {
+                    min_slacks[j] -= delta;
+                }
+            }
+
+            column = next_column;
+            if matched_rows[column] == 0 {
+                break;
+            }
+        }
+
+        // Flip the path, so every row on it gets matched with the next column.
+        while column != 0 {
+            let previous_column = previous_columns[column];
+            matched_rows[column] = matched_rows[previous_column];
+            column = previous_column;
+        }
+    }
+
+    let mut assignment = vec![0; n];
+    for column in 1..=n {
+        assignment[matched_rows[column] - 1] = column - 1;
+    }
+
+    assignment
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const LIST1: [u64; 6] = [3, 4, 2, 1, 3, 3];
+    const LIST2: [u64; 6] = [4, 3, 5, 3, 9, 3];
+
+    #[test]
+    fn sorted_pairing() {
+        let distance = list_distance(&LIST1, &LIST2, Pairing::Sorted, &Metric::Absolute);
+
+        assert_eq!(distance.total, 11);
+        assert_eq!(distance.pairs[0], (1, 3));
+        assert_eq!(distance.pairs[5], (4, 9));
+        assert_eq!(
+            list_distance(&LIST1, &LIST2, Pairing::Sorted, &Metric::Squared).total,
+            35
+        );
+    }
+
+    #[test]
+    fn squared_distance_saturates() {
+        assert_eq!(Metric::Squared.distance(0, 1 << 32), u64::MAX);
+        assert_eq!(
+            Metric::Squared.distance((1 << 32) - 1, 0),
+            0xffff_fffe_0000_0001
+        );
+
+        let (list1, list2) = ([0, u64::MAX], [u64::MAX, 0]);
+        let total = |pairing| list_distance(&list1, &list2, pairing, &Metric::Squared).total;
+
+        assert_eq!(total(Pairing::Sorted), 0);
+        assert_eq!(total(Pairing::Optimal), 0);
+        assert_eq!(total(Pairing::Positional), u64::MAX);
+    }
+
+    #[test]
+    fn positional_pairing() {
+        let distance = list_distance(&LIST1, &LIST2, Pairing::Positional, &Metric::Absolute);
+
+        assert_eq!(distance.total, 13);
+        assert_eq!(distance.pairs[2], (2, 5));
+    }
+
+    #[test]
+    fn optimal_pairing() {
+        // For the absolute difference, pairing sorted lists is optimal.
+        let distance = list_distance(&LIST1, &LIST2, Pairing::Optimal, &Metric::Absolute);
+
+        assert_eq!(distance.total, 11);
+        assert_eq!(distance.pairs.len(), 6);
+
+        // Counts mismatched pairs, for which sorting is not optimal.
+        let mismatch = Metric::custom(|a, b| (a != b) as u64);
+        let (list1, list2) = ([1, 2, 3], [3, 1, 5]);
+
+        assert_eq!(
+            list_distance(&list1, &list2, Pairing::Sorted, &mismatch).total,
+            2
+        );
+
+        let distance = list_distance(&list1, &list2, Pairing::Optimal, &mismatch);
+
+        assert_eq!(distance.total, 1);
+        assert_eq!(distance.pairs, [(1, 1), (2, 5), (3, 3)]);
+    }
+
+    #[test]
+    fn optimal_pairing_empty() {
+        let distance = list_distance(&[], &[], Pairing::Optimal, &Metric::Absolute);
+
+        assert_eq!(distance.total, 0);
+        assert!(distance.pairs.is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn optimal_pairing_matches_brute_force(
+            lists in (0..6usize).prop_flat_map(|len| (
+                prop::collection::vec(0..30u64, len),
+                prop::collection::vec(0..30u64, len),
+            )),
+        ) {
+            let (list1, list2) = lists;
+            let metric = Metric::custom(|a, b| (a * 7 + b * 3) % 11);
+
+            let best_total = list2
+                .iter()
+                .permutations(list2.len())
+                .map(|list2| list1.iter().zip(list2).map(|(&a, &b)| metric.distance(a, b)).sum())
+                .min()
+                .unwrap_or(0);
+            let distance = list_distance(&list1, &list2, Pairing::Optimal, &metric);
+
+            prop_assert_eq!(distance.total, best_total);
+            prop_assert_eq!(
+                distance.pairs.iter().map(|&(a, _)| a).collect_vec(),
+                list1
+            );
+        }
+    }
+}
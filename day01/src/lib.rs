@@ -1,30 +1,123 @@
+pub mod distance;
 mod parse;
 
-use parse::parse_input;
+use std::{collections::HashMap, io::BufRead};
+
+use common::{Answer, ParseError, ReadError, Solution};
+use distance::{list_distance, Metric, Pairing};
+pub use parse::PairReader;
+use parse::{parse_input, read_input};
+
+fn total_distance(list1: &[u64], list2: &[u64]) -> u64 {
+    list_distance(list1, list2, Pairing::Sorted, &Metric::Absolute).total
+}
+
+pub fn try_solve_part_1(input: &str) -> Result<u64, ParseError> {
+    let (list1, list2) = parse_input(input)?;
+
+    Ok(total_distance(&list1, &list2))
+}
 
 pub fn solve_part_1(input: &str) -> u64 {
-    let (mut list1, mut list2) = parse_input(input);
-    assert_eq!(list1.len(), list2.len());
+    try_solve_part_1(input).expect("Failed to parse puzzle input")
+}
 
-    list1.sort();
-    list2.sort();
+pub fn try_solve_part_1_from_reader(reader: impl BufRead) -> Result<u64, ReadError> {
+    let (list1, list2) = read_input(reader)?;
+
+    Ok(total_distance(&list1, &list2))
+}
+
+pub fn solve_part_1_from_reader(reader: impl BufRead) -> u64 {
+    try_solve_part_1_from_reader(reader).expect("Failed to read puzzle input")
+}
+
+/// Sums every number of `list1` multiplied by the number of its occurrences in `list2`.
+pub fn similarity_score(list1: &[u64], list2: &[u64]) -> u64 {
+    let mut counts = HashMap::new();
+    for &r in list2 {
+        *counts.entry(r).or_insert(0) += 1;
+    }
 
     list1
-        .into_iter()
-        .zip(list2)
-        .map(|(a, b)| a.abs_diff(b))
+        .iter()
+        .map(|&l| l * counts.get(&l).copied().unwrap_or(0))
         .sum()
 }
 
+pub fn try_solve_part_2(input: &str) -> Result<u64, ParseError> {
+    let (list1, list2) = parse_input(input)?;
+
+    Ok(similarity_score(&list1, &list2))
+}
+
 pub fn solve_part_2(input: &str) -> u64 {
-    let (list1, list2) = parse_input(input);
-    assert_eq!(list1.len(), list2.len());
+    try_solve_part_2(input).expect("Failed to parse puzzle input")
+}
 
-    list1
-        .into_iter()
-        .map(|l| {
-            let count = list2.iter().filter(|&&r| l == r).count() as u64;
-            l * count
-        })
-        .sum()
+pub fn try_solve_part_2_from_reader(reader: impl BufRead) -> Result<u64, ReadError> {
+    let (list1, list2) = read_input(reader)?;
+
+    Ok(similarity_score(&list1, &list2))
+}
+
+pub fn solve_part_2_from_reader(reader: impl BufRead) -> u64 {
+    try_solve_part_2_from_reader(reader).expect("Failed to read puzzle input")
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+    const EXAMPLE: &'static str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+    type Input = (Vec<u64>, Vec<u64>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1((list1, list2): &Self::Input) -> Answer {
+        total_distance(list1, list2).into()
+    }
+
+    fn part_2((list1, list2): &Self::Input) -> Answer {
+        similarity_score(list1, list2).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn similarity_score_naive(list1: &[u64], list2: &[u64]) -> u64 {
+        list1
+            .iter()
+            .map(|&l| {
+                let count = list2.iter().filter(|&&r| l == r).count() as u64;
+                l * count
+            })
+            .sum()
+    }
+
+    proptest! {
+        #[test]
+        fn similarity_score_matches_naive(
+            list1 in prop::collection::vec(0..20u64, 0..50),
+            list2 in prop::collection::vec(0..20u64, 0..50),
+        ) {
+            prop_assert_eq!(
+                similarity_score(&list1, &list2),
+                similarity_score_naive(&list1, &list2)
+            );
+        }
+    }
 }
@@ -1,9 +1,12 @@
-use std::fs;
-
+use common::{
+    input::{InputStore, REAL},
+    Solution,
+};
 use day01::*;
 
 fn main() {
-    let contents = fs::read_to_string("day01/input.txt")
+    let contents = InputStore::from_env()
+        .and_then(|store| store.load(Day01::DAY, REAL))
         .expect("Failed to read puzzle input");
 
     println!("Part 1: {}", solve_part_1(&contents));
@@ -15,12 +18,7 @@ fn main() {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3";
+    const INPUT: &str = Day01::EXAMPLE;
 
     #[test]
     fn test_part_1() {
@@ -35,4 +33,10 @@ mod tests {
 
         assert_eq!(sol, 31);
     }
+
+    #[test]
+    fn test_from_reader() {
+        assert_eq!(solve_part_1_from_reader(INPUT.as_bytes()), 11);
+        assert_eq!(solve_part_2_from_reader(INPUT.as_bytes()), 31);
+    }
 }
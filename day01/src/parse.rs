@@ -1,8 +1,10 @@
+use std::io::BufRead;
+
+use common::{parse::parse_all, ParseError, ReadError};
 use nom::{character::complete::*, combinator::*, multi::*, sequence::*, Finish, IResult};
 
-pub fn parse_input(input: &str) -> (Vec<u64>, Vec<u64>) {
-    let (_, lists) = lists(input).finish().expect("Failed to parse puzzle input");
-    lists
+pub fn parse_input(input: &str) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+    parse_all(input, lists)
 }
 
 fn number(input: &str) -> IResult<&str, u64> {
@@ -10,7 +12,8 @@ fn number(input: &str) -> IResult<&str, u64> {
 }
 
 fn number_pair(input: &str) -> IResult<&str, (u64, u64)> {
-    separated_pair(number, space1, number)(input)
+    // Once a row has started, errors point at the offending token instead of the whole row.
+    pair(number, cut(preceded(space1, number)))(input)
 }
 
 fn lists(input: &str) -> IResult<&str, (Vec<u64>, Vec<u64>)> {
@@ -19,6 +22,60 @@ fn lists(input: &str) -> IResult<&str, (Vec<u64>, Vec<u64>)> {
     })(input)
 }
 
+/// Streams pairs of locations from a reader, one row at a time.
+///
+/// Rows are read into a single reused buffer, so the input is never held in memory as a whole.
+/// Blank rows are skipped.
+pub struct PairReader<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+}
+
+impl<R: BufRead> PairReader<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            line: String::new(),
+            line_number: 0,
+        }
+    }
+
+    fn parse_line(&self) -> Result<(u64, u64), ParseError> {
+        let line = self.line.trim_end_matches(['\n', '\r']);
+        let result = all_consuming(terminated(number_pair, space0))(line).finish();
+
+        result.map(|(_, pair)| pair).map_err(|err| ParseError {
+            line: self.line_number,
+            ..ParseError::from_nom(line, err)
+        })
+    }
+}
+
+impl<R: BufRead> Iterator for PairReader<R> {
+    type Item = Result<(u64, u64), ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => self.line_number += 1,
+                Err(err) => return Some(Err(err.into())),
+            }
+
+            if !self.line.trim().is_empty() {
+                return Some(self.parse_line().map_err(ReadError::from));
+            }
+        }
+    }
+}
+
+/// Reads both lists of locations from a reader.
+pub fn read_input(reader: impl BufRead) -> Result<(Vec<u64>, Vec<u64>), ReadError> {
+    PairReader::new(reader).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use nom::Finish;
@@ -53,4 +110,42 @@ mod tests {
         assert_eq!(lists, (vec![1, 3], vec![2, 4]));
         assert!(rem.is_empty());
     }
+
+    #[test]
+    fn test_parse_input_malformed() {
+        let input = "1   2
+3   x";
+
+        let error = parse_input(input).unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 5);
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn test_read_input() {
+        let input = "1   2\r\n3   4\n\n5 6  \n";
+
+        let lists = read_input(input.as_bytes()).unwrap();
+
+        assert_eq!(lists, (vec![1, 3, 5], vec![2, 4, 6]));
+    }
+
+    #[test]
+    fn test_read_input_malformed() {
+        let input = "1   2
+
+3   x
+5   6";
+
+        let error = match read_input(input.as_bytes()) {
+            Err(ReadError::Parse(error)) => error,
+            result => panic!("Expected parse error, got {result:?}"),
+        };
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 5);
+        assert_eq!(error.text, "x");
+    }
 }
@@ -0,0 +1,224 @@
+use std::fmt;
+
+use itertools::Itertools;
+use serde::Serialize;
+
+use crate::policy::{Monotonicity, SafetyPolicy};
+
+/// Rule broken by a pair of adjacent levels, the first of them at `index`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum Violation {
+    /// Levels differ by a step outside of the allowed range.
+    StepOutOfRange { index: usize, levels: (u64, u64) },
+    /// Levels change in the opposite direction than the required or preceding ones.
+    DirectionChange { index: usize, levels: (u64, u64) },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rule, index, (a, b)) = match *self {
+            Violation::StepOutOfRange { index, levels } => ("step out of range", index, levels),
+            Violation::DirectionChange { index, levels } => ("direction change", index, levels),
+        };
+
+        write!(f, "{rule} at levels {index} and {}: {a} -> {b}", index + 1)
+    }
+}
+
+/// Safety of a single report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Classification {
+    Safe,
+    /// Safe after the dampener removes levels at given indices.
+    Dampened {
+        removed: Vec<usize>,
+    },
+    /// Unsafe even with the dampener, failing first on `violation`.
+    Unsafe {
+        violation: Violation,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnosis {
+    /// Line of the report in the input, starting at 1.
+    pub line: usize,
+    pub levels: Vec<u64>,
+    #[serde(flatten)]
+    pub classification: Classification,
+}
+
+impl SafetyPolicy {
+    /// Returns the first rule broken by the report as a whole, without the dampener.
+    pub fn first_violation(&self, report: &[u64]) -> Option<Violation> {
+        let mut increasing = match self.monotonicity {
+            Monotonicity::Increasing => Some(true),
+            Monotonicity::Decreasing => Some(false),
+            Monotonicity::Either | Monotonicity::None => None,
+        };
+
+        for (index, (&a, &b)) in report.iter().tuple_windows().enumerate() {
+            let levels = (a, b);
+            if !(self.min_step..=self.max_step).contains(&a.abs_diff(b)) {
+                return Some(Violation::StepOutOfRange { index, levels });
+            }
+
+            if self.monotonicity == Monotonicity::None || a == b {
+                continue;
+            }
+
+            match increasing {
+                None => increasing = Some(a < b),
+                Some(increasing) if increasing != (a < b) => {
+                    return Some(Violation::DirectionChange { index, levels });
+                }
+                Some(_) => {}
+            }
+        }
+
+        None
+    }
+
+    pub fn classify(&self, report: &[u64]) -> Classification {
+        match self.levels_to_remove(report) {
+            Some(removed) if removed.is_empty() => Classification::Safe,
+            Some(removed) => Classification::Dampened { removed },
+            None => Classification::Unsafe {
+                violation: self
+                    .first_violation(report)
+                    .expect("Unsafe report must break some rule"),
+            },
+        }
+    }
+}
+
+pub fn diagnose_reports(reports: &[Vec<u64>], policy: &SafetyPolicy) -> Vec<Diagnosis> {
+    reports
+        .iter()
+        .enumerate()
+        .map(|(idx, report)| Diagnosis {
+            line: idx + 1,
+            levels: report.clone(),
+            classification: policy.classify(report),
+        })
+        .collect()
+}
+
+/// Renders diagnoses as a human readable table.
+pub fn render_table(diagnoses: &[Diagnosis]) -> String {
+    let mut table = format!("{:>6}  {:<8}  Details\n", "Line", "Status");
+    for diagnosis in diagnoses {
+        let (status, details) = match &diagnosis.classification {
+            Classification::Safe => ("safe", String::new()),
+            Classification::Dampened { removed } => (
+                "dampened",
+                format!("removed levels at {}", removed.iter().join(", ")),
+            ),
+            Classification::Unsafe { violation } => ("unsafe", violation.to_string()),
+        };
+
+        table += format!("{:>6}  {status:<8}  {details}", diagnosis.line).trim_end();
+        table.push('\n');
+    }
+
+    table
+}
+
+/// Renders diagnoses as JSON objects, one per line.
+pub fn render_json_lines(diagnoses: &[Diagnosis]) -> String {
+    diagnoses
+        .iter()
+        .map(|diagnosis| {
+            let mut line =
+                serde_json::to_string(diagnosis).expect("Diagnosis must be serializable");
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse::parse_input, Day02};
+    use common::Solution;
+
+    fn diagnose_example() -> Vec<Diagnosis> {
+        let reports = parse_input(Day02::EXAMPLE).unwrap();
+
+        diagnose_reports(&reports, &SafetyPolicy::dampened())
+    }
+
+    #[test]
+    fn classify_example_reports() {
+        let classifications = diagnose_example()
+            .into_iter()
+            .map(|diagnosis| diagnosis.classification)
+            .collect_vec();
+
+        assert_eq!(
+            classifications,
+            [
+                Classification::Safe,
+                Classification::Unsafe {
+                    violation: Violation::StepOutOfRange {
+                        index: 1,
+                        levels: (2, 7)
+                    }
+                },
+                Classification::Unsafe {
+                    violation: Violation::StepOutOfRange {
+                        index: 2,
+                        levels: (6, 2)
+                    }
+                },
+                Classification::Dampened { removed: vec![1] },
+                Classification::Dampened { removed: vec![2] },
+                Classification::Safe,
+            ]
+        );
+    }
+
+    #[test]
+    fn direction_change_violation() {
+        let policy = SafetyPolicy::default();
+
+        assert_eq!(
+            policy.first_violation(&[1, 3, 2, 4, 5]),
+            Some(Violation::DirectionChange {
+                index: 1,
+                levels: (3, 2)
+            })
+        );
+        assert_eq!(policy.first_violation(&[1, 3, 6, 7, 9]), None);
+    }
+
+    #[test]
+    fn render_diagnoses() {
+        let diagnoses = diagnose_example();
+
+        let table = render_table(&diagnoses);
+        let json_lines = render_json_lines(&diagnoses);
+
+        assert_eq!(
+            table.lines().take(3).collect_vec(),
+            [
+                "  Line  Status    Details",
+                "     1  safe",
+                "     2  unsafe    step out of range at levels 1 and 2: 2 -> 7",
+            ]
+        );
+        assert_eq!(
+            json_lines.lines().nth(1),
+            Some(
+                r#"{"line":2,"levels":[1,2,7,8,9],"status":"unsafe","violation":{"rule":"step_out_of_range","index":1,"levels":[2,7]}}"#
+            )
+        );
+        assert_eq!(
+            json_lines.lines().nth(3),
+            Some(r#"{"line":4,"levels":[1,3,2,4,5],"status":"dampened","removed":[1]}"#)
+        );
+    }
+}
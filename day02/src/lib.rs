@@ -1,54 +1,186 @@
+pub mod diagnostics;
 mod parse;
+pub mod policy;
 
-use itertools::Itertools;
+use common::{Answer, ParseError, Solution};
+use diagnostics::{diagnose_reports, Diagnosis};
 use parse::parse_input;
+use policy::SafetyPolicy;
 
-fn check_report_safe<'a>(report: impl IntoIterator<Item = &'a u64>) -> bool {
-    let report = report.into_iter().collect_vec();
-    let is_increasing = report.iter().tuple_windows().all(|(&&a, &&b)| a <= b);
-    let is_decreasing = report.iter().tuple_windows().all(|(&&a, &&b)| a >= b);
+fn check_report_safe(report: &[u64]) -> bool {
+    SafetyPolicy::default().is_safe(report)
+}
 
-    let has_correct_differences = report
+fn count_safe_reports(reports: &[Vec<u64>]) -> usize {
+    reports
         .iter()
-        .tuple_windows()
-        .all(|(&&a, &&b)| (1..=3).contains(&a.abs_diff(b)));
+        .filter(|report| check_report_safe(report))
+        .count()
+}
+
+pub fn try_solve_part_1(input: &str) -> Result<usize, ParseError> {
+    let reports = parse_input(input)?;
 
-    (is_decreasing || is_increasing) && has_correct_differences
+    Ok(count_safe_reports(&reports))
 }
 
 pub fn solve_part_1(input: &str) -> usize {
-    let reports = parse_input(input);
+    try_solve_part_1(input).expect("Failed to parse puzzle input")
+}
 
+/// Returns indices of the fewest levels, at most `tolerance` of them, whose removal makes the
+/// report safe, or `None` if that's not possible.
+pub fn levels_to_remove(report: &[u64], tolerance: usize) -> Option<Vec<usize>> {
+    SafetyPolicy {
+        dampener_budget: tolerance,
+        ..SafetyPolicy::default()
+    }
+    .levels_to_remove(report)
+}
+
+fn check_report_safe_dampened(report: &[u64]) -> bool {
+    SafetyPolicy::dampened().is_safe(report)
+}
+
+/// Counts reports which are safe under the given policy.
+pub fn count_safe_reports_with_policy(reports: &[Vec<u64>], policy: &SafetyPolicy) -> usize {
     reports
-        .into_iter()
-        .filter(|report| check_report_safe(report))
+        .iter()
+        .filter(|report| policy.is_safe(report))
         .count()
 }
 
-fn check_report_safe_dampened(report: &[u64]) -> bool {
-    if check_report_safe(report) {
-        return true;
+fn count_safe_reports_dampened(reports: &[Vec<u64>]) -> usize {
+    reports
+        .iter()
+        .filter(|report| check_report_safe_dampened(report))
+        .count()
+}
+
+pub fn try_solve_part_2(input: &str) -> Result<usize, ParseError> {
+    let reports = parse_input(input)?;
+
+    Ok(count_safe_reports_dampened(&reports))
+}
+
+pub fn solve_part_2(input: &str) -> usize {
+    try_solve_part_2(input).expect("Failed to parse puzzle input")
+}
+
+/// Classifies every report of the input under the given policy.
+pub fn try_diagnose(input: &str, policy: &SafetyPolicy) -> Result<Vec<Diagnosis>, ParseError> {
+    let reports = parse_input(input)?;
+
+    Ok(diagnose_reports(&reports, policy))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
+    const EXAMPLE: &'static str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
     }
 
-    for i in 0..report.len() {
-        if check_report_safe(
-            report
-                .iter()
-                .enumerate()
-                .filter_map(|(j, r)| (i != j).then_some(r)),
-        ) {
-            return true;
-        }
+    fn part_1(reports: &Self::Input) -> Answer {
+        count_safe_reports(reports).into()
     }
 
-    false
+    fn part_2(reports: &Self::Input) -> Answer {
+        count_safe_reports_dampened(reports).into()
+    }
 }
 
-pub fn solve_part_2(input: &str) -> usize {
-    let reports = parse_input(input);
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use proptest::prelude::*;
 
-    reports
-        .into_iter()
-        .filter(|report| check_report_safe_dampened(report))
-        .count()
+    use super::*;
+
+    fn remove_levels(report: &[u64], removed: &[usize]) -> Vec<u64> {
+        (0..report.len())
+            .filter(|i| !removed.contains(i))
+            .map(|i| report[i])
+            .collect()
+    }
+
+    /// Checks rules of part 1 directly, independently of [`SafetyPolicy`].
+    fn check_report_safe_naive(report: &[u64]) -> bool {
+        let is_increasing = report.iter().tuple_windows().all(|(a, b)| a <= b);
+        let is_decreasing = report.iter().tuple_windows().all(|(a, b)| a >= b);
+        let has_correct_differences = report
+            .iter()
+            .tuple_windows()
+            .all(|(&a, &b)| (1..=3).contains(&a.abs_diff(b)));
+
+        (is_decreasing || is_increasing) && has_correct_differences
+    }
+
+    #[test]
+    fn levels_to_remove_examples() {
+        assert_eq!(levels_to_remove(&[7, 6, 4, 2, 1], 1), Some(vec![]));
+        assert_eq!(levels_to_remove(&[1, 2, 7, 8, 9], 1), None);
+        assert_eq!(levels_to_remove(&[9, 7, 6, 2, 1], 1), None);
+        assert_eq!(levels_to_remove(&[9, 7, 6, 2, 1], 2), Some(vec![3, 4]));
+        assert_eq!(levels_to_remove(&[1, 3, 6, 7, 9], 0), Some(vec![]));
+        assert_eq!(levels_to_remove(&[], 0), Some(vec![]));
+        assert_eq!(levels_to_remove(&[5, 5], 0), None);
+        assert_eq!(
+            levels_to_remove(&[5, 5], 1).map(|removed| removed.len()),
+            Some(1)
+        );
+
+        let removed = levels_to_remove(&[1, 3, 2, 4, 5], 1).unwrap();
+        assert_eq!(removed.len(), 1);
+        assert!(check_report_safe_naive(&remove_levels(
+            &[1, 3, 2, 4, 5],
+            &removed
+        )));
+    }
+
+    #[test]
+    fn levels_to_remove_prefers_earliest_removals() {
+        // Removals from the puzzle description: the second level and the third level.
+        assert_eq!(levels_to_remove(&[1, 3, 2, 4, 5], 1), Some(vec![1]));
+        assert_eq!(levels_to_remove(&[8, 6, 4, 4, 1], 1), Some(vec![2]));
+        assert_eq!(levels_to_remove(&[5, 5], 1), Some(vec![1]));
+        assert_eq!(levels_to_remove(&[1, 2, 2, 2, 3], 2), Some(vec![1, 2]));
+    }
+
+    proptest! {
+        #[test]
+        fn check_report_safe_matches_naive(report in prop::collection::vec(0..12u64, 0..9)) {
+            prop_assert_eq!(check_report_safe(&report), check_report_safe_naive(&report));
+        }
+
+        #[test]
+        fn levels_to_remove_matches_brute_force(
+            report in prop::collection::vec(0..12u64, 0..9),
+            tolerance in 0..4usize,
+        ) {
+            let fewest_removed = (0..=tolerance.min(report.len())).find(|&num_removed| {
+                (0..report.len())
+                    .combinations(num_removed)
+                    .any(|removed| check_report_safe_naive(&remove_levels(&report, &removed)))
+            });
+
+            let removed = levels_to_remove(&report, tolerance);
+
+            prop_assert_eq!(removed.as_ref().map(Vec::len), fewest_removed);
+            if let Some(removed) = removed {
+                prop_assert!(check_report_safe_naive(&remove_levels(&report, &removed)));
+            }
+        }
+    }
 }
@@ -1,26 +1,77 @@
-use std::fs;
+use std::{path::PathBuf, process::ExitCode};
 
-use day02::*;
+use clap::Parser;
+use common::{
+    input::{InputStore, REAL},
+    Solution,
+};
+use day02::{
+    diagnostics::{render_json_lines, render_table},
+    policy::SafetyPolicy,
+    *,
+};
 
-fn main() {
-    let contents = fs::read_to_string("day02/input.txt")
+#[derive(Parser)]
+#[command(about = "Solves day 2 of Advent of Code 2024")]
+struct Cli {
+    /// Name of the cached puzzle input.
+    #[arg(short, long, default_value = REAL)]
+    name: String,
+
+    /// Classify every report instead of solving the puzzle.
+    #[arg(short, long)]
+    diagnose: bool,
+
+    /// Print diagnostics as JSON lines instead of a table.
+    #[arg(short, long, requires = "diagnose")]
+    json: bool,
+
+    /// Path to a TOML or JSON safety policy, part 2 rules are used by default.
+    #[arg(short, long, requires = "diagnose")]
+    policy: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let contents = InputStore::from_env()
+        .and_then(|store| store.load(Day02::DAY, &cli.name))
         .expect("Failed to read puzzle input");
 
-    println!("Part 1: {}", solve_part_1(&contents));
+    if !cli.diagnose {
+        println!("Part 1: {}", solve_part_1(&contents));
+
+        println!("Part 2: {}", solve_part_2(&contents));
+
+        return ExitCode::SUCCESS;
+    }
+
+    let policy = match &cli.policy {
+        Some(path) => match SafetyPolicy::load(path) {
+            Ok(policy) => policy,
+            Err(err) => {
+                eprintln!("Failed to load policy from {}: {err}", path.display());
+                return ExitCode::FAILURE;
+            }
+        },
+        None => SafetyPolicy::dampened(),
+    };
+
+    let diagnoses = try_diagnose(&contents, &policy).expect("Failed to parse puzzle input");
+    if cli.json {
+        print!("{}", render_json_lines(&diagnoses));
+    } else {
+        print!("{}", render_table(&diagnoses));
+    }
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    ExitCode::SUCCESS
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9";
+    const INPUT: &str = Day02::EXAMPLE;
 
     #[test]
     fn test_part_1() {
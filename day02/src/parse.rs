@@ -1,10 +1,8 @@
-use nom::{character::complete::*, combinator::*, multi::*, Finish, IResult};
+use common::{parse::parse_all, ParseError};
+use nom::{character::complete::*, combinator::*, multi::*, IResult};
 
-pub fn parse_input(input: &str) -> Vec<Vec<u64>> {
-    let (_, reports) = report_list(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
-    reports
+pub fn parse_input(input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    parse_all(input, report_list)
 }
 
 fn number(input: &str) -> IResult<&str, u64> {
@@ -0,0 +1,289 @@
+use std::{fs, io, path::Path};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+/// Direction in which levels of a safe report must change.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Monotonicity {
+    Increasing,
+    Decreasing,
+    /// Either increasing or decreasing, but the same throughout the report.
+    #[default]
+    Either,
+    /// Levels may go up and down.
+    None,
+}
+
+/// Rules deciding whether a report is safe, which by default are the ones from part 1.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SafetyPolicy {
+    /// Smallest allowed difference between adjacent levels.
+    pub min_step: u64,
+    /// Largest allowed difference between adjacent levels.
+    pub max_step: u64,
+    pub monotonicity: Monotonicity,
+    /// Number of levels the dampener may remove to make a report safe.
+    pub dampener_budget: usize,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        Self {
+            min_step: 1,
+            max_step: 3,
+            monotonicity: Monotonicity::Either,
+            dampener_budget: 0,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    /// Rules of part 2, allowing the dampener to remove a single level.
+    pub fn dampened() -> Self {
+        Self {
+            dampener_budget: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Loads policy from a `.json` or `.toml` file.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let policy: Self = if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            serde_json::from_str(&contents)?
+        } else {
+            toml::from_str(&contents)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?
+        };
+
+        if policy.min_step > policy.max_step {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Minimal step {} is larger than maximal step {}",
+                    policy.min_step, policy.max_step
+                ),
+            ));
+        }
+
+        Ok(policy)
+    }
+
+    fn is_safe_step(&self, a: u64, b: u64, increasing: Option<bool>) -> bool {
+        let correct_direction = match increasing {
+            Some(true) => a <= b,
+            Some(false) => a >= b,
+            None => true,
+        };
+
+        correct_direction && (self.min_step..=self.max_step).contains(&a.abs_diff(b))
+    }
+
+    /// Allowed directions of change between adjacent levels, `None` meaning any direction.
+    fn directions(&self) -> &'static [Option<bool>] {
+        match self.monotonicity {
+            Monotonicity::Increasing => &[Some(true)],
+            Monotonicity::Decreasing => &[Some(false)],
+            Monotonicity::Either => &[Some(true), Some(false)],
+            Monotonicity::None => &[None],
+        }
+    }
+
+    pub fn is_safe(&self, report: &[u64]) -> bool {
+        if self.dampener_budget > 0 {
+            return self.levels_to_remove(report).is_some();
+        }
+
+        // Without the dampener, a single scan over adjacent levels is enough.
+        self.directions().iter().any(|&increasing| {
+            report
+                .iter()
+                .tuple_windows()
+                .all(|(&a, &b)| self.is_safe_step(a, b, increasing))
+        })
+    }
+
+    /// Returns indices of the fewest levels, at most `dampener_budget` of them, whose removal
+    /// makes the report safe, or `None` if that's not possible.
+    ///
+    /// Runs in O(n·k²) time for a report of n levels and budget of k levels.
+    pub fn levels_to_remove(&self, report: &[u64]) -> Option<Vec<usize>> {
+        self.directions()
+            .iter()
+            .filter_map(|&increasing| {
+                levels_to_remove_with(report, self.dampener_budget, |a, b| {
+                    self.is_safe_step(a, b, increasing)
+                })
+            })
+            .min_by_key(|removed| removed.len())
+    }
+}
+
+/// Finds the fewest levels to remove, so that every pair of adjacent kept levels is safe.
+fn levels_to_remove_with(
+    report: &[u64],
+    tolerance: usize,
+    is_safe_step: impl Fn(u64, u64) -> bool,
+) -> Option<Vec<usize>> {
+    // More levels than the report has can't be removed, so the table stays small.
+    let tolerance = tolerance.min(report.len());
+    let len = report.len();
+
+    // For every kept level `i` and number of levels `r` removed before it, holds the previous
+    // kept level, `None` if `i` is the first kept one, or nothing if there's no such safe prefix.
+    let mut previous: Vec<Vec<Option<Option<usize>>>> = vec![vec![None; tolerance + 1]; len];
+    for i in 0..len {
+        if i <= tolerance {
+            previous[i][i] = Some(None);
+        }
+
+        // Nearest levels go first, so removals are preferably made as early as possible.
+        for j in (i.saturating_sub(tolerance + 1)..i).rev() {
+            if !is_safe_step(report[j], report[i]) {
+                continue;
+            }
+
+            let skipped = i - j - 1;
+            for r in skipped..=tolerance {
+                if previous[i][r].is_none() && previous[j][r - skipped].is_some() {
+                    previous[i][r] = Some(Some(j));
+                }
+            }
+        }
+    }
+
+    // Levels after the last kept one are removed as well.
+    let num_removed = |(i, r): (usize, usize)| r + len - 1 - i;
+    let Some(mut last) = (len.saturating_sub(tolerance + 1)..len)
+        .cartesian_product(0..=tolerance)
+        .filter(|&(i, r)| previous[i][r].is_some() && num_removed((i, r)) <= tolerance)
+        .min_by_key(|&last| num_removed(last))
+    else {
+        return (len == 0).then(Vec::new);
+    };
+
+    let mut kept = vec![false; len];
+    loop {
+        let (i, r) = last;
+        kept[i] = true;
+        match previous[i][r].unwrap() {
+            Some(j) => last = (j, r - (i - j - 1)),
+            None => break,
+        }
+    }
+
+    Some((0..len).filter(|&i| !kept[i]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn policy_monotonicity() {
+        let report = [1, 4, 2, 5];
+        let policy = |monotonicity| SafetyPolicy {
+            monotonicity,
+            ..SafetyPolicy::default()
+        };
+
+        assert!(!policy(Monotonicity::Either).is_safe(&report));
+        assert!(policy(Monotonicity::None).is_safe(&report));
+        assert!(policy(Monotonicity::Increasing).is_safe(&[1, 2, 5]));
+        assert!(!policy(Monotonicity::Decreasing).is_safe(&[1, 2, 5]));
+    }
+
+    #[test]
+    fn policy_step_range() {
+        let policy = SafetyPolicy {
+            min_step: 0,
+            max_step: 10,
+            ..SafetyPolicy::default()
+        };
+
+        assert!(policy.is_safe(&[1, 1, 11, 20]));
+        assert!(!policy.is_safe(&[1, 1, 12, 5]));
+        assert!(!SafetyPolicy::default().is_safe(&[1, 1, 2]));
+    }
+
+    #[test]
+    fn policy_dampener_budget() {
+        let report = [1, 9, 2, 9, 3];
+
+        assert_eq!(SafetyPolicy::dampened().levels_to_remove(&report), None);
+
+        let policy = SafetyPolicy {
+            dampener_budget: 2,
+            ..SafetyPolicy::default()
+        };
+        assert_eq!(policy.levels_to_remove(&report), Some(vec![1, 3]));
+    }
+
+    #[test]
+    fn policy_huge_dampener_budget() {
+        for dampener_budget in [100_000_000_000, usize::MAX] {
+            let policy = SafetyPolicy {
+                dampener_budget,
+                ..SafetyPolicy::default()
+            };
+
+            assert!(policy.is_safe(&[1, 2, 3]));
+            assert_eq!(policy.levels_to_remove(&[1, 9, 2]), Some(vec![1]));
+            assert_eq!(policy.levels_to_remove(&[5, 5]), Some(vec![1]));
+            assert_eq!(policy.levels_to_remove(&[]), Some(vec![]));
+        }
+    }
+
+    #[test]
+    fn load_policy() {
+        let dir = env::temp_dir().join(format!("aoc-day02-policy-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let expected = SafetyPolicy {
+            min_step: 2,
+            max_step: 5,
+            monotonicity: Monotonicity::Increasing,
+            dampener_budget: 0,
+        };
+
+        let toml_path = dir.join("policy.toml");
+        fs::write(
+            &toml_path,
+            "min_step = 2\nmax_step = 5\nmonotonicity = \"increasing\"\n",
+        )
+        .unwrap();
+        let json_path = dir.join("policy.json");
+        fs::write(&json_path, serde_json::to_string(&expected).unwrap()).unwrap();
+        let invalid_path = dir.join("invalid.toml");
+        fs::write(&invalid_path, "min_step = 4\nmax_step = 3\n").unwrap();
+
+        assert_eq!(SafetyPolicy::load(&toml_path).unwrap(), expected);
+        assert_eq!(SafetyPolicy::load(&json_path).unwrap(), expected);
+        assert!(SafetyPolicy::load(&invalid_path).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn load_policy_with_huge_dampener_budget() {
+        let dir = env::temp_dir().join(format!("aoc-day02-budget-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("policy.toml");
+        fs::write(&path, "dampener_budget = 100000000000\n").unwrap();
+
+        let policy = SafetyPolicy::load(&path).unwrap();
+
+        assert_eq!(policy.dampener_budget, 100_000_000_000);
+        assert!(policy.is_safe(&[1, 2, 3]));
+        assert!(policy.is_safe(&[1, 9, 2, 9, 3]));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,121 @@
+use crate::interpreter::{Instruction, Machine, Registry};
+
+/// How accepted instructions are marked in the corrupted memory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HighlightStyle {
+    /// Terminal colours: green for executed, dimmed red for disabled and cyan for toggles.
+    #[default]
+    Ansi,
+    /// Plain text markers: `[executed]`, `{disabled}` and `<toggle>`.
+    Brackets,
+}
+
+/// Role of an accepted instruction during execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    Executed,
+    /// Skipped because the machine was disabled, e.g. by an earlier `don't()`.
+    Disabled,
+    /// Unconditional instruction, such as `do()` or `don't()`.
+    Toggle,
+}
+
+impl Mark {
+    fn delimiters(self, style: HighlightStyle) -> (&'static str, &'static str) {
+        match (style, self) {
+            (HighlightStyle::Ansi, Mark::Executed) => ("\x1b[1;32m", "\x1b[0m"),
+            (HighlightStyle::Ansi, Mark::Disabled) => ("\x1b[2;31m", "\x1b[0m"),
+            (HighlightStyle::Ansi, Mark::Toggle) => ("\x1b[36m", "\x1b[0m"),
+            (HighlightStyle::Brackets, Mark::Executed) => ("[", "]"),
+            (HighlightStyle::Brackets, Mark::Disabled) => ("{", "}"),
+            (HighlightStyle::Brackets, Mark::Toggle) => ("<", ">"),
+        }
+    }
+}
+
+/// Runs the instructions and marks each of them by whether it was executed.
+pub fn mark_instructions(instructions: &[Instruction], registry: &Registry) -> Vec<Mark> {
+    let mut machine = Machine::default();
+    instructions
+        .iter()
+        .map(|instruction| {
+            let mark = if registry
+                .get(instruction.name)
+                .is_some_and(|def| def.unconditional)
+            {
+                Mark::Toggle
+            } else if registry.is_enabled(&machine, instruction) {
+                Mark::Executed
+            } else {
+                Mark::Disabled
+            };
+            registry.execute(&mut machine, instruction);
+            mark
+        })
+        .collect()
+}
+
+/// Renders the corrupted memory with instructions parsed from it highlighted.
+///
+/// Instructions must be parsed from `input`, as their spans are used for highlighting.
+pub fn render_highlighted(
+    input: &str,
+    instructions: &[Instruction],
+    registry: &Registry,
+    style: HighlightStyle,
+) -> String {
+    let marks = mark_instructions(instructions, registry);
+
+    let mut rendered = String::with_capacity(input.len());
+    let mut end = 0;
+    for (instruction, mark) in instructions.iter().zip(marks) {
+        let span = instruction.span;
+        let (open, close) = mark.delimiters(style);
+
+        rendered += &input[end..span.offset];
+        rendered += open;
+        rendered += &input[span.range()];
+        rendered += close;
+        end = span.end();
+    }
+    rendered += &input[end..];
+
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_input;
+
+    const INPUT: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn highlight_brackets() {
+        let registry = Registry::standard();
+        let instructions = parse_input(INPUT).unwrap();
+
+        let rendered =
+            render_highlighted(INPUT, &instructions, &registry, HighlightStyle::Brackets);
+
+        assert_eq!(
+            rendered,
+            "x[mul(2,4)]&mul[3,7]!^<don't()>_{mul(5,5)}+mul(32,64]({mul(11,8)}un<do()>?[mul(8,5)])"
+        );
+    }
+
+    #[test]
+    fn highlight_ansi() {
+        let registry = Registry::standard();
+        let instructions = parse_input("mul(1,2)don't()").unwrap();
+
+        let rendered = render_highlighted(
+            "mul(1,2)don't()",
+            &instructions,
+            &registry,
+            HighlightStyle::Ansi,
+        );
+
+        assert_eq!(rendered, "\x1b[1;32mmul(1,2)\x1b[0m\x1b[36mdon't()\x1b[0m");
+    }
+}
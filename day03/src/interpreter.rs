@@ -0,0 +1,289 @@
+use std::ops::{Range, RangeInclusive};
+
+/// Location of a fragment of the corrupted memory, in bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub offset: usize,
+    pub len: usize,
+}
+
+impl Span {
+    pub fn new(offset: usize, len: usize) -> Self {
+        Self { offset, len }
+    }
+
+    pub fn end(&self) -> usize {
+        self.offset + self.len
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.offset..self.end()
+    }
+}
+
+/// Call of a registered instruction, as found in the corrupted memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub name: &'static str,
+    pub args: Vec<u64>,
+    /// Where the call was found, empty at the start for instructions not parsed from memory.
+    pub span: Span,
+}
+
+impl Instruction {
+    pub fn new(name: &'static str, args: impl Into<Vec<u64>>) -> Self {
+        Self {
+            name,
+            args: args.into(),
+            span: Span::default(),
+        }
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+}
+
+/// State of the machine executing instructions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    /// Sum of results of all executed instructions.
+    pub accumulator: i64,
+    /// Whether instructions other than the unconditional ones are executed.
+    pub enabled: bool,
+    /// Number of executed instructions.
+    pub num_executed: usize,
+    /// Number of instructions skipped because the machine was disabled.
+    pub num_skipped: usize,
+    /// Number of instructions rejected because their result would overflow the accumulator.
+    pub num_rejected: usize,
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self {
+            accumulator: 0,
+            enabled: true,
+            num_executed: 0,
+            num_skipped: 0,
+            num_rejected: 0,
+        }
+    }
+}
+
+/// Definition of an instruction, i.e. its name, arity and semantics.
+#[derive(Debug, Clone)]
+pub struct InstructionDef {
+    pub name: &'static str,
+    /// Allowed numbers of arguments, calls with other numbers are treated as corrupted.
+    pub arity: RangeInclusive<usize>,
+    /// Whether the instruction is executed even when the machine is disabled, as toggles are.
+    pub unconditional: bool,
+    /// Executes the call, returning `false` and leaving the machine unchanged if it's rejected.
+    pub execute: fn(&mut Machine, &[u64]) -> bool,
+}
+
+/// Known instructions, by which the memory is parsed and executed.
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    instructions: Vec<InstructionDef>,
+}
+
+impl Registry {
+    /// Instructions of the puzzle: `mul` of two numbers, `do` and `don't`.
+    pub fn standard() -> Self {
+        let mut registry = Self::default();
+        registry.register(InstructionDef {
+            name: "mul",
+            arity: 2..=2,
+            unconditional: false,
+            execute: |machine, args| accumulate(machine, product(args)),
+        });
+        registry.register(InstructionDef {
+            name: "do",
+            arity: 0..=0,
+            unconditional: true,
+            execute: |machine, _| {
+                machine.enabled = true;
+                true
+            },
+        });
+        registry.register(InstructionDef {
+            name: "don't",
+            arity: 0..=0,
+            unconditional: true,
+            execute: |machine, _| {
+                machine.enabled = false;
+                true
+            },
+        });
+        registry
+    }
+
+    /// Standard instructions, with `mul` taking any number of at least two arguments, and
+    /// `add` and `sub` of any number of arguments.
+    pub fn extended() -> Self {
+        let mut registry = Self::standard();
+        registry.register(InstructionDef {
+            name: "mul",
+            arity: 2..=usize::MAX,
+            unconditional: false,
+            execute: |machine, args| accumulate(machine, product(args)),
+        });
+        registry.register(InstructionDef {
+            name: "add",
+            arity: 1..=usize::MAX,
+            unconditional: false,
+            execute: |machine, args| accumulate(machine, sum(args)),
+        });
+        registry.register(InstructionDef {
+            name: "sub",
+            arity: 1..=usize::MAX,
+            unconditional: false,
+            execute: |machine, args| {
+                let difference = sum(&args[1..])
+                    .and_then(|subtrahend| i64::try_from(args[0]).ok()?.checked_sub(subtrahend));
+                accumulate(machine, difference)
+            },
+        });
+        registry
+    }
+
+    /// Adds instruction, replacing the one with the same name.
+    pub fn register(&mut self, instruction: InstructionDef) {
+        self.instructions
+            .retain(|existing| existing.name != instruction.name);
+        self.instructions.push(instruction);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&InstructionDef> {
+        self.instructions
+            .iter()
+            .find(|instruction| instruction.name == name)
+    }
+
+    pub fn instructions(&self) -> &[InstructionDef] {
+        &self.instructions
+    }
+
+    /// Returns whether the instruction would be executed in the current state of the machine.
+    ///
+    /// Panics if the instruction isn't registered.
+    pub fn is_enabled(&self, machine: &Machine, instruction: &Instruction) -> bool {
+        machine.enabled || self.def(instruction).unconditional
+    }
+
+    fn def(&self, instruction: &Instruction) -> &InstructionDef {
+        self.get(instruction.name)
+            .unwrap_or_else(|| panic!("Instruction `{}` is not registered", instruction.name))
+    }
+
+    /// Executes single instruction, unless the machine is disabled.
+    ///
+    /// Calls rejected by the instruction, e.g. on overflow, are counted but otherwise ignored.
+    ///
+    /// Panics if the instruction isn't registered or has wrong number of arguments.
+    pub fn execute(&self, machine: &mut Machine, instruction: &Instruction) {
+        let def = self.def(instruction);
+        assert!(def.arity.contains(&instruction.args.len()));
+
+        if !machine.enabled && !def.unconditional {
+            machine.num_skipped += 1;
+        } else if (def.execute)(machine, &instruction.args) {
+            machine.num_executed += 1;
+        } else {
+            machine.num_rejected += 1;
+        }
+    }
+
+    /// Executes all instructions on a fresh machine and returns its final state.
+    pub fn run<'a>(&self, instructions: impl IntoIterator<Item = &'a Instruction>) -> Machine {
+        let mut machine = Machine::default();
+        for instruction in instructions {
+            self.execute(&mut machine, instruction);
+        }
+        machine
+    }
+}
+
+/// Adds result of a call to the accumulator, rejecting the call if there's none or it overflows.
+fn accumulate(machine: &mut Machine, result: Option<i64>) -> bool {
+    match result.and_then(|result| machine.accumulator.checked_add(result)) {
+        Some(accumulator) => {
+            machine.accumulator = accumulator;
+            true
+        }
+        None => false,
+    }
+}
+
+fn product(args: &[u64]) -> Option<i64> {
+    args.iter().try_fold(1i64, |product, &arg| {
+        product.checked_mul(arg.try_into().ok()?)
+    })
+}
+
+fn sum(args: &[u64]) -> Option<i64> {
+    args.iter()
+        .try_fold(0i64, |sum, &arg| sum.checked_add(arg.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::parse_with;
+
+    #[test]
+    fn run_extended_instructions() {
+        let registry = Registry::extended();
+        let input = "add(1,2,3)mul(2,3,4)don't()sub(10,1)do()sub(10,1,2)";
+
+        let instructions = parse_with(input, &registry).unwrap();
+        let machine = registry.run(&instructions);
+
+        assert_eq!(
+            instructions[0],
+            Instruction::new("add", [1, 2, 3]).with_span(Span::new(0, 10))
+        );
+        assert_eq!(machine.accumulator, 6 + 24 + 7);
+        assert!(machine.enabled);
+        assert_eq!(machine.num_executed, 5);
+        assert_eq!(machine.num_skipped, 1);
+    }
+
+    #[test]
+    fn register_custom_instruction() {
+        let mut registry = Registry::standard();
+        registry.register(InstructionDef {
+            name: "neg",
+            arity: 0..=0,
+            unconditional: false,
+            execute: |machine, _| {
+                machine.accumulator = -machine.accumulator;
+                true
+            },
+        });
+
+        let instructions = parse_with("mul(2,3)neg()mul(1,2,3)", &registry).unwrap();
+        let machine = registry.run(&instructions);
+
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(machine.accumulator, -6);
+    }
+
+    #[test]
+    fn reject_overflowing_calls() {
+        let registry = Registry::extended();
+        let large = "mul(999,999,999,999,999,999)".repeat(10);
+        let input = format!("mul(2,3)mul(999,999,999,999,999,999,999){large}sub(0,1)");
+
+        let instructions = parse_with(&input, &registry).unwrap();
+        let machine = registry.run(&instructions);
+
+        assert_eq!(instructions.len(), 13);
+        assert_eq!(machine.accumulator, 6 + 9 * 999i64.pow(6) - 1);
+        assert_eq!(machine.num_executed, 11);
+        assert_eq!(machine.num_rejected, 2);
+    }
+}
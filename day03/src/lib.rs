@@ -1,40 +1,101 @@
+pub mod highlight;
+pub mod interpreter;
 mod parse;
+pub mod stream;
 
+use std::io::{self, Read};
+
+use common::{Answer, ParseError, Solution};
+use highlight::{render_highlighted, HighlightStyle};
+pub use interpreter::Instruction;
+use interpreter::Registry;
 use parse::parse_input;
+pub use parse::Scanner;
+use stream::StreamEvaluator;
+
+fn sum_multiplications(instructions: &[Instruction]) -> i64 {
+    let muls = instructions
+        .iter()
+        .filter(|instruction| instruction.name == "mul");
+
+    Registry::standard().run(muls).accumulator
+}
+
+pub fn try_solve_part_1(input: &str) -> Result<i64, ParseError> {
+    let instructions = parse_input(input)?;
+
+    Ok(sum_multiplications(&instructions))
+}
+
+pub fn solve_part_1(input: &str) -> i64 {
+    try_solve_part_1(input).expect("Failed to parse puzzle input")
+}
+
+fn sum_enabled_multiplications(instructions: &[Instruction]) -> i64 {
+    Registry::standard().run(instructions).accumulator
+}
+
+pub fn try_solve_part_2(input: &str) -> Result<i64, ParseError> {
+    let instructions = parse_input(input)?;
+
+    Ok(sum_enabled_multiplications(&instructions))
+}
+
+pub fn solve_part_2(input: &str) -> i64 {
+    try_solve_part_2(input).expect("Failed to parse puzzle input")
+}
+
+/// Solves part 2 while reading the input in chunks, without holding all of it in memory.
+pub fn try_solve_part_2_from_reader(mut reader: impl Read) -> io::Result<i64> {
+    let mut evaluator = StreamEvaluator::default();
+    let mut chunk = [0; 8192];
+    loop {
+        match reader.read(&mut chunk) {
+            Ok(0) => break,
+            Ok(len) => evaluator.push(&chunk[..len]),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(evaluator.finish().accumulator)
+}
+
+pub fn solve_part_2_from_reader(reader: impl Read) -> i64 {
+    try_solve_part_2_from_reader(reader).expect("Failed to read puzzle input")
+}
+
+/// Renders the input with instructions of part 2 highlighted, see [`render_highlighted`].
+pub fn try_highlight(input: &str, style: HighlightStyle) -> Result<String, ParseError> {
+    let instructions = parse_input(input)?;
+
+    Ok(render_highlighted(
+        input,
+        &instructions,
+        &Registry::standard(),
+        style,
+    ))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+    const EXAMPLE: &'static str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(instructions: &Self::Input) -> Answer {
+        sum_multiplications(instructions).into()
+    }
 
-#[derive(Debug, PartialEq, Eq)]
-enum Instruction {
-    Mul(u64, u64),
-    Do,
-    Dont,
-}
-
-pub fn solve_part_1(input: &str) -> u64 {
-    let instructions = parse_input(input);
-
-    instructions
-        .into_iter()
-        .map(|instruction| match instruction {
-            Instruction::Mul(a, b) => a * b,
-            _ => 0,
-        })
-        .sum()
-}
-
-#[allow(unused)]
-pub fn solve_part_2(input: &str) -> u64 {
-    let instructions = parse_input(input);
-
-    instructions
-        .into_iter()
-        .fold(
-            (0, true),
-            |(total, is_enabled), instruction| match instruction {
-                Instruction::Do => (total, true),
-                Instruction::Dont => (total, false),
-                Instruction::Mul(a, b) if is_enabled => (total + a * b, is_enabled),
-                _ => (total, is_enabled),
-            },
-        )
-        .0
+    fn part_2(instructions: &Self::Input) -> Answer {
+        sum_enabled_multiplications(instructions).into()
+    }
 }
@@ -1,11 +1,44 @@
-use std::fs;
+use clap::Parser;
+use common::{
+    input::{InputStore, REAL},
+    Solution,
+};
+use day03::{highlight::HighlightStyle, *};
 
-use day03::*;
+#[derive(Parser)]
+#[command(about = "Solves day 3 of Advent of Code 2024")]
+struct Cli {
+    /// Name of the cached puzzle input.
+    #[arg(short, long, default_value = REAL)]
+    name: String,
+
+    /// Print the input with accepted instructions highlighted instead of solving the puzzle.
+    #[arg(short = 'H', long)]
+    highlight: bool,
+
+    /// Mark instructions with brackets instead of colours.
+    #[arg(short, long, requires = "highlight")]
+    brackets: bool,
+}
 
 fn main() {
-    let contents = fs::read_to_string("day03/input.txt")
+    let cli = Cli::parse();
+
+    let contents = InputStore::from_env()
+        .and_then(|store| store.load(Day03::DAY, &cli.name))
         .expect("Failed to read puzzle input");
 
+    if cli.highlight {
+        let style = if cli.brackets {
+            HighlightStyle::Brackets
+        } else {
+            HighlightStyle::Ansi
+        };
+        let rendered = try_highlight(&contents, style).expect("Failed to parse puzzle input");
+        print!("{rendered}");
+        return;
+    }
+
     println!("Part 1: {}", solve_part_1(&contents));
 
     println!("Part 2: {}", solve_part_2(&contents));
@@ -24,7 +57,7 @@ mod tests {
         assert_eq!(sol, 161);
     }
 
-    const INPUT2: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+    const INPUT2: &str = Day03::EXAMPLE;
 
     #[test]
     fn test_part_2() {
@@ -32,4 +65,9 @@ mod tests {
 
         assert_eq!(sol, 48);
     }
+
+    #[test]
+    fn test_part_2_from_reader() {
+        assert_eq!(solve_part_2_from_reader(INPUT2.as_bytes()), 48);
+    }
 }
@@ -1,52 +1,185 @@
-use nom::{
-    branch::*, bytes::complete::*, character::complete::*, combinator::*, multi::*, sequence::*,
-    Finish, IResult,
-};
+use common::ParseError;
 
-use crate::Instruction;
+use crate::interpreter::{Instruction, Registry, Span};
 
-pub fn parse_input(input: &str) -> Vec<Instruction> {
-    let (_, instructions) = instructions(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
+/// Maximum number of digits of an operand, as stated by the puzzle.
+pub const MAX_DIGITS: usize = 3;
 
-    instructions
+/// Parses instructions of the puzzle, see [`Registry::standard`].
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_with(input, &Registry::standard())
 }
 
-fn number(input: &str) -> IResult<&str, u64> {
-    map_res(digit1, str::parse)(input)
+/// Parses calls of instructions known to the registry, skipping everything else.
+///
+/// Every instruction records its [`Span`] within `input`.
+pub fn parse_with(input: &str, registry: &Registry) -> Result<Vec<Instruction>, ParseError> {
+    let instructions = Scanner::new(input.as_bytes(), registry).collect::<Vec<_>>();
+    if instructions.is_empty() {
+        return Err(ParseError::at_offset(input, 0, "expected an instruction"));
+    }
+
+    Ok(instructions)
+}
+
+/// Reason why no call starts at some position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mismatch {
+    Invalid,
+    /// The input ends with a prefix of a call, which might be completed by more input.
+    Incomplete,
 }
 
-fn params(input: &str) -> IResult<&str, (u64, u64)> {
-    delimited(
-        char('('),
-        separated_pair(number, char(','), number),
-        char(')'),
-    )(input)
+/// Lazily finds calls of registered instructions in corrupted memory, in a single pass.
+///
+/// Only bytes which can start an instruction name are inspected further, everything else is
+/// skipped. Operands must have 1 to [`MAX_DIGITS`] digits, calls with longer ones or with more
+/// operands than the instruction takes are ignored.
+pub struct Scanner<'a> {
+    input: &'a [u8],
+    position: usize,
+    registry: &'a Registry,
+    starts_name: [bool; 256],
+    /// Whether more input may follow, so calls cut off by the end must not be skipped.
+    partial: bool,
 }
 
-fn mul_instruction(input: &str) -> IResult<&str, Instruction> {
-    map(preceded(tag("mul"), params), |(a, b)| {
-        Instruction::Mul(a, b)
-    })(input)
+impl<'a> Scanner<'a> {
+    pub fn new(input: &'a [u8], registry: &'a Registry) -> Self {
+        let mut starts_name = [false; 256];
+        for def in registry.instructions() {
+            if let Some(&first) = def.name.as_bytes().first() {
+                starts_name[first as usize] = true;
+            }
+        }
+
+        Self {
+            input,
+            position: 0,
+            registry,
+            starts_name,
+            partial: false,
+        }
+    }
+
+    /// Creates scanner of input which may be continued, stopping before a call cut off by its end.
+    pub fn partial(input: &'a [u8], registry: &'a Registry) -> Self {
+        Self {
+            partial: true,
+            ..Self::new(input, registry)
+        }
+    }
+
+    /// Offset of the first byte which wasn't scanned yet.
+    ///
+    /// Once a partial scanner is exhausted, the input before it can be discarded.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Parses call of the first registered instruction starting exactly at `start`.
+    fn call_at(&self, start: usize) -> Result<Instruction, Mismatch> {
+        let rest = &self.input[start..];
+        for def in self.registry.instructions() {
+            let name = def.name.as_bytes();
+            let result = match rest.strip_prefix(name) {
+                Some(params) => parse_params(params, *def.arity.end()).and_then(|(args, len)| {
+                    if !def.arity.contains(&args.len()) {
+                        return Err(Mismatch::Invalid);
+                    }
+                    let span = Span::new(start, name.len() + len);
+                    Ok(Instruction::new(def.name, args).with_span(span))
+                }),
+                None if name.starts_with(rest) => Err(Mismatch::Incomplete),
+                None => Err(Mismatch::Invalid),
+            };
+
+            if result != Err(Mismatch::Invalid) {
+                return result;
+            }
+        }
+
+        Err(Mismatch::Invalid)
+    }
 }
 
-fn do_instruction(input: &str) -> IResult<&str, Instruction> {
-    map(tag("do()"), |_| Instruction::Do)(input)
+impl Iterator for Scanner<'_> {
+    type Item = Instruction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.position < self.input.len() {
+            let Some(skipped) = self.input[self.position..]
+                .iter()
+                .position(|&byte| self.starts_name[byte as usize])
+            else {
+                self.position = self.input.len();
+                return None;
+            };
+            let start = self.position + skipped;
+
+            match self.call_at(start) {
+                Ok(instruction) => {
+                    self.position = instruction.span.end();
+                    return Some(instruction);
+                }
+                Err(Mismatch::Incomplete) if self.partial => {
+                    self.position = start;
+                    return None;
+                }
+                Err(_) => self.position = start + 1,
+            }
+        }
+
+        None
+    }
 }
 
-fn dont_instruction(input: &str) -> IResult<&str, Instruction> {
-    map(tag("don't()"), |_| Instruction::Dont)(input)
+/// Parses parenthesized, comma separated list of at most `max_args` operands, returning it with
+/// its length in bytes.
+fn parse_params(input: &[u8], max_args: usize) -> Result<(Vec<u64>, usize), Mismatch> {
+    let mut rest = match input.split_first() {
+        Some((b'(', rest)) => rest,
+        Some(_) => return Err(Mismatch::Invalid),
+        None => return Err(Mismatch::Incomplete),
+    };
+    let mut args = Vec::new();
+    if let Some(after) = rest.strip_prefix(b")") {
+        return Ok((args, input.len() - after.len()));
+    }
+
+    loop {
+        let (number, after) = parse_number(rest)?;
+        args.push(number);
+
+        match after.split_first() {
+            Some((b',', after)) if args.len() < max_args => rest = after,
+            Some((b')', after)) => return Ok((args, input.len() - after.len())),
+            Some(_) => return Err(Mismatch::Invalid),
+            None => return Err(Mismatch::Incomplete),
+        }
+    }
 }
 
-fn instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
-    many1(map(
-        many_till(
-            anychar,
-            alt((mul_instruction, do_instruction, dont_instruction)),
-        ),
-        |(_, i)| i,
-    ))(input)
+fn parse_number(input: &[u8]) -> Result<(u64, &[u8]), Mismatch> {
+    let len = input
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if len > MAX_DIGITS {
+        return Err(Mismatch::Invalid);
+    }
+    if len == input.len() {
+        return Err(Mismatch::Incomplete);
+    }
+    if len == 0 {
+        return Err(Mismatch::Invalid);
+    }
+
+    let number = input[..len]
+        .iter()
+        .fold(0, |number, &digit| number * 10 + u64::from(digit - b'0'));
+
+    Ok((number, &input[len..]))
 }
 
 #[cfg(test)]
@@ -55,35 +188,92 @@ mod tests {
 
     #[test]
     fn test_parse_params() {
-        let input = "(1,2)";
-
-        let (rem, params) = params(input).unwrap();
-
-        assert_eq!(params, (1, 2));
-        assert!(rem.is_empty());
+        assert_eq!(parse_params(b"(1,2)", 2), Ok((vec![1, 2], 5)));
+        assert_eq!(parse_params(b"()rest", 2), Ok((vec![], 2)));
+        assert_eq!(parse_params(b"(123,4)", 2), Ok((vec![123, 4], 7)));
+        assert_eq!(parse_params(b"(1234,4)", 2), Err(Mismatch::Invalid));
+        assert_eq!(parse_params(b"(32,64]", 2), Err(Mismatch::Invalid));
+        assert_eq!(parse_params(b"(1,)", 2), Err(Mismatch::Invalid));
+        assert_eq!(parse_params(b"( 1,2)", 2), Err(Mismatch::Invalid));
+        assert_eq!(parse_params(b"(12", 2), Err(Mismatch::Incomplete));
+        assert_eq!(parse_params(b"(123,", 2), Err(Mismatch::Incomplete));
+        assert_eq!(parse_params(b"(1234", 2), Err(Mismatch::Invalid));
+        assert_eq!(parse_params(b"(1,2,", 2), Err(Mismatch::Invalid));
+        assert_eq!(parse_params(b"(1,2,3)", 2), Err(Mismatch::Invalid));
+        assert_eq!(parse_params(b"(1,2,3)", 3), Ok((vec![1, 2, 3], 7)));
     }
 
     #[test]
     fn test_parse_mul_instruction() {
-        let input = "mul(3,4)";
+        let registry = Registry::standard();
 
-        let (rem, instruction) = mul_instruction(input).unwrap();
+        let instructions = Scanner::new(b"mul(3,4)", &registry).collect::<Vec<_>>();
 
-        assert_eq!(instruction, Instruction::Mul(3, 4));
-        assert!(rem.is_empty());
+        assert_eq!(
+            instructions,
+            [Instruction::new("mul", [3, 4]).with_span(Span::new(0, 8))]
+        );
+        assert_eq!(Scanner::new(b"mul(3,4,5)", &registry).count(), 0);
+        assert_eq!(Scanner::new(b"mul(32,64]", &registry).count(), 0);
+        assert_eq!(Scanner::new(b"mul(1000,2)", &registry).count(), 0);
     }
 
     #[test]
     fn test_parse_instructions() {
         let input = "a?dmul(1,3)h27don't()mul(3,7)md.8do()";
 
-        let (rem, instructions) = instructions(input).unwrap();
+        let instructions = parse_input(input).unwrap();
 
         assert_eq!(instructions.len(), 4);
-        assert_eq!(instructions[0], Instruction::Mul(1, 3));
-        assert_eq!(instructions[1], Instruction::Dont);
-        assert_eq!(instructions[2], Instruction::Mul(3, 7));
-        assert_eq!(instructions[3], Instruction::Do);
-        assert!(rem.is_empty());
+        assert_eq!(
+            instructions[0],
+            Instruction::new("mul", [1, 3]).with_span(Span::new(3, 8))
+        );
+        assert_eq!(
+            instructions[1],
+            Instruction::new("don't", []).with_span(Span::new(14, 7))
+        );
+        assert_eq!(
+            instructions[2],
+            Instruction::new("mul", [3, 7]).with_span(Span::new(21, 8))
+        );
+        assert_eq!(
+            instructions[3],
+            Instruction::new("do", []).with_span(Span::new(33, 4))
+        );
+        assert_eq!(&input[instructions[2].span.range()], "mul(3,7)");
+    }
+
+    #[test]
+    fn test_scanner_is_lazy_and_resumes_after_failed_match() {
+        let registry = Registry::standard();
+        let input = "mmul(mul(2,3)ümul(4,5)";
+
+        let mut scanner = Scanner::new(input.as_bytes(), &registry);
+
+        assert_eq!(scanner.next().map(|i| i.span), Some(Span::new(5, 8)));
+        assert_eq!(scanner.next().map(|i| i.args), Some(vec![4, 5]));
+        assert_eq!(scanner.next(), None);
+    }
+
+    #[test]
+    fn test_partial_scanner_stops_at_cut_off_call() {
+        let registry = Registry::standard();
+
+        let mut scanner = Scanner::partial(b"mul(1,2)xdo()mul(3,4", &registry);
+
+        assert_eq!(scanner.by_ref().count(), 2);
+        assert_eq!(scanner.position(), 13);
+        assert_eq!(Scanner::new(b"mul(1,2)xdo()mul(3,4", &registry).count(), 2);
+
+        let mut scanner = Scanner::partial(b"mul(1,2)xdon", &registry);
+
+        assert_eq!(scanner.by_ref().count(), 1);
+        assert_eq!(scanner.position(), 9);
+    }
+
+    #[test]
+    fn test_parse_without_instructions() {
+        assert!(parse_input("mul[1,2]").is_err());
     }
 }
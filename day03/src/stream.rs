@@ -0,0 +1,141 @@
+use crate::{
+    interpreter::{Machine, Registry},
+    parse::Scanner,
+};
+
+/// Evaluates corrupted memory fed in chunks, e.g. as it's read from a pipe.
+///
+/// Calls split across chunks are buffered until they can be decided, while the state of the
+/// machine, including the enabled flag, is carried over between chunks. A call is decided as soon
+/// as it has more operands than its instruction takes, so for instructions of bounded arity only a
+/// few bytes are ever buffered.
+#[derive(Debug, Clone)]
+pub struct StreamEvaluator {
+    registry: Registry,
+    machine: Machine,
+    /// Unscanned tail of the memory, which may start a call continued by the next chunk.
+    pending: Vec<u8>,
+}
+
+impl StreamEvaluator {
+    pub fn new(registry: Registry) -> Self {
+        Self {
+            registry,
+            machine: Machine::default(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Executes all calls completed by the chunk.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.pending.extend_from_slice(chunk);
+
+        let mut scanner = Scanner::partial(&self.pending, &self.registry);
+        for instruction in scanner.by_ref() {
+            self.registry.execute(&mut self.machine, &instruction);
+        }
+        let scanned = scanner.position();
+        self.pending.drain(..scanned);
+    }
+
+    /// State of the machine after all calls completed so far.
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    /// Ends the memory, dropping any call left unfinished, and returns the final state.
+    pub fn finish(self) -> Machine {
+        self.machine
+    }
+}
+
+impl Default for StreamEvaluator {
+    fn default() -> Self {
+        Self::new(Registry::standard())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::parse::parse_with;
+
+    const INPUT: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    fn evaluate_chunked(input: &[u8], chunk_size: usize) -> Machine {
+        let mut evaluator = StreamEvaluator::default();
+        for chunk in input.chunks(chunk_size) {
+            evaluator.push(chunk);
+        }
+        evaluator.finish()
+    }
+
+    #[test]
+    fn stream_split_at_every_position() {
+        for split in 0..=INPUT.len() {
+            let (first, second) = INPUT.as_bytes().split_at(split);
+            let mut evaluator = StreamEvaluator::default();
+
+            evaluator.push(first);
+            evaluator.push(second);
+
+            assert_eq!(evaluator.finish().accumulator, 48, "split at {split}");
+        }
+
+        assert_eq!(evaluate_chunked(INPUT.as_bytes(), 1).accumulator, 48);
+    }
+
+    #[test]
+    fn stream_carries_state_between_chunks() {
+        let mut evaluator = StreamEvaluator::default();
+
+        evaluator.push(b"mul(2,3)don'");
+        assert_eq!(evaluator.machine().accumulator, 6);
+        assert!(evaluator.machine().enabled);
+
+        evaluator.push(b"t()mul(4,");
+        assert!(!evaluator.machine().enabled);
+
+        evaluator.push(b"5)do()mul(12");
+        evaluator.push(b"34,1)mul(1,1");
+
+        let machine = evaluator.finish();
+        assert_eq!(machine.accumulator, 6);
+        assert_eq!(machine.num_skipped, 1);
+    }
+
+    #[test]
+    fn stream_drops_calls_with_too_many_operands() {
+        let mut evaluator = StreamEvaluator::default();
+
+        evaluator.push(b"mul(");
+        for _ in 0..20_000 {
+            evaluator.push(b"1,");
+            assert!(evaluator.pending.len() <= 12);
+        }
+        evaluator.push(b"mul(2,3)");
+
+        assert!(evaluator.pending.is_empty());
+        assert_eq!(evaluator.finish().accumulator, 6);
+    }
+
+    proptest! {
+        #[test]
+        fn stream_matches_whole_input(
+            input in "[mul(don't),0-9x]{0,64}",
+            chunk_size in 1..8usize,
+        ) {
+            let registry = Registry::extended();
+            let expected = registry.run(&parse_with(&input, &registry).unwrap_or_default());
+
+            let mut evaluator = StreamEvaluator::new(registry);
+            for chunk in input.as_bytes().chunks(chunk_size) {
+                evaluator.push(chunk);
+            }
+
+            prop_assert_eq!(evaluator.finish(), expected);
+        }
+    }
+}
@@ -1,43 +1,76 @@
-mod grid;
+pub mod search;
 
-use grid::Grid;
+use common::{Answer, ParseError, Solution};
+use grid::{Grid, Window};
+use search::WordSearch;
 
-pub fn solve_part_1(input: &str) -> usize {
-    let grid: Grid = input.parse().expect("Failed to parse puzzle input");
+fn window_diagonals(window: Window<'_, char>) -> [String; 2] {
+    [
+        window.diagonal().collect(),
+        window.anti_diagonal().collect(),
+    ]
+}
 
-    let xmas = ["XMAS", "SAMX"];
+fn count_xmas(grid: &Grid<char>) -> usize {
+    WordSearch::new(["XMAS"]).count(grid)
+}
 
-    let horizontal_xmas: usize = grid
-        .get_horizontals()
-        .into_iter()
-        .map(|row| xmas.iter().map(|x| row.matches(x).count()).sum::<usize>())
-        .sum();
+pub fn try_solve_part_1(input: &str) -> Result<usize, ParseError> {
+    let grid: Grid<char> = input.parse()?;
+
+    Ok(count_xmas(&grid))
+}
 
-    let vertical_xmas: usize = grid
-        .get_verticals()
-        .into_iter()
-        .map(|row| xmas.iter().map(|x| row.matches(x).count()).sum::<usize>())
-        .sum();
+pub fn solve_part_1(input: &str) -> usize {
+    try_solve_part_1(input).expect("Failed to parse puzzle input")
+}
 
-    let diagonal_xmas = grid
-        .iter_subgrid(4, 4)
-        .flat_map(|grid| grid.get_diagonals())
-        .filter(|string| ["XMAS", "SAMX"].contains(&string.as_str()))
-        .count();
+fn count_x_mas(grid: &Grid<char>) -> usize {
+    let mas = ["MAS", "SAM"];
 
-    horizontal_xmas + vertical_xmas + diagonal_xmas
+    grid.windows(3, 3)
+        .map(window_diagonals)
+        .filter(|[diag1, diag2]| mas.contains(&diag1.as_str()) && mas.contains(&diag2.as_str()))
+        .count()
+}
+
+pub fn try_solve_part_2(input: &str) -> Result<usize, ParseError> {
+    let grid: Grid<char> = input.parse()?;
+
+    Ok(count_x_mas(&grid))
 }
 
-#[allow(unused)]
 pub fn solve_part_2(input: &str) -> usize {
-    let grid: Grid = input.parse().expect("Failed to parse puzzle input");
+    try_solve_part_2(input).expect("Failed to parse puzzle input")
+}
 
-    let mas = ["MAS", "SAM"];
+pub struct Day04;
 
-    grid.iter_subgrid(3, 3)
-        .map(|grid| grid.get_diagonals())
-        .filter(|[diag1, diag2]| {
-            mas.contains(&diag1.as_str()) && mas.contains(&diag2.as_str())
-        })
-        .count()
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
+    const EXAMPLE: &'static str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(grid: &Self::Input) -> Answer {
+        count_xmas(grid).into()
+    }
+
+    fn part_2(grid: &Self::Input) -> Answer {
+        count_x_mas(grid).into()
+    }
 }
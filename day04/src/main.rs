@@ -1,9 +1,12 @@
-use std::fs;
-
+use common::{
+    input::{InputStore, REAL},
+    Solution,
+};
 use day04::*;
 
 fn main() {
-    let contents = fs::read_to_string("day04/input.txt")
+    let contents = InputStore::from_env()
+        .and_then(|store| store.load(Day04::DAY, REAL))
         .expect("Failed to read puzzle input");
 
     println!("Part 1: {}", solve_part_1(&contents));
@@ -15,16 +18,7 @@ fn main() {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX";
+    const INPUT: &str = Day04::EXAMPLE;
 
     #[test]
     fn test_part_1() {
@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+
+use grid::{Direction8, Grid, Position};
+
+/// Occurrence of a word in a grid, read from `start` in `direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match<'a> {
+    pub word: &'a str,
+    pub start: Position,
+    pub direction: Direction8,
+}
+
+impl Match<'_> {
+    /// Positions of all letters of the word, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        std::iter::successors(Some(self.start), |&position| {
+            Some(position.step(self.direction))
+        })
+        .take(self.word.chars().count())
+    }
+
+    /// Position of the last letter of the word.
+    pub fn end(&self) -> Position {
+        self.positions().last().unwrap_or(self.start)
+    }
+}
+
+/// Finds words written in any of eight directions in a grid of letters.
+///
+/// Matches covering the same cells are reported once, so palindromes, words together with their
+/// reverses or single letters aren't counted several times. Overlapping matches are all reported.
+#[derive(Debug, Clone)]
+pub struct WordSearch {
+    words: Vec<Vec<char>>,
+    names: Vec<String>,
+}
+
+impl WordSearch {
+    /// Creates search for given words, ignoring empty ones.
+    pub fn new<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> Self {
+        let names = words
+            .into_iter()
+            .map(|word| word.as_ref().to_string())
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>();
+        let words = names.iter().map(|word| word.chars().collect()).collect();
+
+        Self { words, names }
+    }
+
+    /// Returns all matches, ordered by start position, word and direction.
+    pub fn find_all(&self, grid: &Grid<char>) -> Vec<Match<'_>> {
+        let mut covered = HashSet::new();
+        let mut matches = Vec::new();
+        for (start, &letter) in grid.iter() {
+            for (word, name) in self.words.iter().zip(&self.names) {
+                if word[0] != letter {
+                    continue;
+                }
+
+                for direction in Direction8::ALL {
+                    let found = grid.line(start, direction).take(word.len()).eq(word.iter());
+                    if !found {
+                        continue;
+                    }
+
+                    let found = Match {
+                        word: name,
+                        start,
+                        direction,
+                    };
+                    // Straight lines are identified by their ends, regardless of reading order.
+                    let (end, len) = (found.end(), word.len());
+                    if covered.insert((start.min(end), start.max(end), len)) {
+                        matches.push(found);
+                    }
+                }
+            }
+        }
+
+        matches
+    }
+
+    pub fn count(&self, grid: &Grid<char>) -> usize {
+        self.find_all(grid).len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day04;
+    use common::Solution;
+
+    fn grid(source: &str) -> Grid<char> {
+        source.parse().unwrap()
+    }
+
+    #[test]
+    fn search_reports_start_and_direction() {
+        let grid = grid("...X\n..M.\n.A..\nS...");
+
+        let search = WordSearch::new(["XMAS"]);
+
+        let matches = search.find_all(&grid);
+
+        assert_eq!(
+            matches,
+            [Match {
+                word: "XMAS",
+                start: Position::new(3, 0),
+                direction: Direction8::DownLeft,
+            }]
+        );
+        assert_eq!(matches[0].end(), Position::new(0, 3));
+        assert!(WordSearch::new(["XMASS"]).find_all(&grid).is_empty());
+    }
+
+    #[test]
+    fn search_all_directions() {
+        let grid = grid(Day04::EXAMPLE);
+
+        let search = WordSearch::new(["XMAS"]);
+
+        let matches = search.find_all(&grid);
+        let directions = matches
+            .iter()
+            .map(|found| found.direction)
+            .collect::<HashSet<_>>();
+
+        assert_eq!(matches.len(), 18);
+        assert_eq!(directions.len(), 8);
+        assert_eq!(WordSearch::new(["XMAS", "SAMX"]).count(&grid), 18);
+    }
+
+    #[test]
+    fn search_without_double_counting() {
+        let grid = grid("ABAB\nXXXX\nAAAA");
+
+        assert_eq!(WordSearch::new(["ABA"]).count(&grid), 1);
+        assert_eq!(WordSearch::new(["BAB", "ABA", "ABA"]).count(&grid), 2);
+        assert_eq!(WordSearch::new(["AAA"]).count(&grid), 2);
+        assert_eq!(WordSearch::new(["X"]).count(&grid), 4);
+        assert_eq!(WordSearch::new(["XX", ""]).count(&grid), 3);
+    }
+}
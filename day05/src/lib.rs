@@ -1,9 +1,10 @@
 pub(crate) mod manual;
 mod parse;
 
+use common::{Answer, ParseError, Solution};
 use itertools::Itertools;
 use manual::*;
-use parse::parse_input;
+use parse::{parse_input, PuzzleInput};
 
 fn check_rule(rule: &OrderingRule, update: &[Page]) -> bool {
     let before_idx = update
@@ -21,9 +22,7 @@ fn check_rules(rules: &[OrderingRule], update: &[Page]) -> bool {
     rules.iter().all(|rule| check_rule(rule, update))
 }
 
-pub fn solve_part_1(input: &str) -> u64 {
-    let puzzle_input = parse_input(input);
-
+fn sum_correct_middle_pages(puzzle_input: &PuzzleInput) -> u64 {
     puzzle_input
         .updates
         .iter()
@@ -35,6 +34,16 @@ pub fn solve_part_1(input: &str) -> u64 {
         .sum()
 }
 
+pub fn try_solve_part_1(input: &str) -> Result<u64, ParseError> {
+    let puzzle_input = parse_input(input)?;
+
+    Ok(sum_correct_middle_pages(&puzzle_input))
+}
+
+pub fn solve_part_1(input: &str) -> u64 {
+    try_solve_part_1(input).expect("Failed to parse puzzle input")
+}
+
 fn fix_update(rules: &[OrderingRule], update: &mut [Page]) {
     for rule in rules.iter().cycle() {
         let Some(before_idx) = update.iter().position(|&page| page == rule.before) else {
@@ -60,13 +69,12 @@ fn fix_update(rules: &[OrderingRule], update: &mut [Page]) {
     }
 }
 
-pub fn solve_part_2(input: &str) -> u64 {
-    let puzzle_input = parse_input(input);
-
+fn sum_fixed_middle_pages(puzzle_input: &PuzzleInput) -> u64 {
     puzzle_input
         .updates
-        .into_iter()
+        .iter()
         .filter(|update| !check_rules(&puzzle_input.ordering_rules, update))
+        .cloned()
         .update(|update| fix_update(&puzzle_input.ordering_rules, update))
         .map(|update| {
             let middle_idx = update.len() / 2;
@@ -74,3 +82,62 @@ pub fn solve_part_2(input: &str) -> u64 {
         })
         .sum()
 }
+
+pub fn try_solve_part_2(input: &str) -> Result<u64, ParseError> {
+    let puzzle_input = parse_input(input)?;
+
+    Ok(sum_fixed_middle_pages(&puzzle_input))
+}
+
+pub fn solve_part_2(input: &str) -> u64 {
+    try_solve_part_2(input).expect("Failed to parse puzzle input")
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+    const EXAMPLE: &'static str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(puzzle_input: &Self::Input) -> Answer {
+        sum_correct_middle_pages(puzzle_input).into()
+    }
+
+    fn part_2(puzzle_input: &Self::Input) -> Answer {
+        sum_fixed_middle_pages(puzzle_input).into()
+    }
+}
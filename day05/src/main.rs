@@ -1,9 +1,12 @@
-use std::fs;
-
+use common::{
+    input::{InputStore, REAL},
+    Solution,
+};
 use day05::*;
 
 fn main() {
-    let contents = fs::read_to_string("day05/input.txt")
+    let contents = InputStore::from_env()
+        .and_then(|store| store.load(Day05::DAY, REAL))
         .expect("Failed to read puzzle input");
 
     println!("Part 1: {}", solve_part_1(&contents));
@@ -15,34 +18,7 @@ fn main() {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "47|53
-97|13
-97|61
-97|47
-75|29
-61|13
-75|53
-29|13
-97|29
-53|29
-61|53
-97|53
-61|29
-47|13
-75|47
-97|75
-47|61
-75|61
-47|29
-75|13
-53|13
-
-75,47,61,53,29
-97,61,53,29,13
-75,29,13
-75,97,47,61,53
-61,13,29
-97,13,75,29,47";
+    const INPUT: &str = Day05::EXAMPLE;
 
     #[test]
     fn test_part_1() {
@@ -1,6 +1,7 @@
+use common::{parse::parse_all, ParseError};
 use nom::{
     character::complete::*, combinator::*, multi::*, sequence::*,
-    Finish, IResult,
+    IResult,
 };
 
 use crate::manual::*;
@@ -10,12 +11,8 @@ pub struct PuzzleInput {
     pub updates: Vec<Vec<Page>>,
 }
 
-pub fn parse_input(input: &str) -> PuzzleInput {
-    let (_, puzzle_input) = puzzle_input(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
-
-    puzzle_input
+pub fn parse_input(input: &str) -> Result<PuzzleInput, ParseError> {
+    parse_all(input, puzzle_input)
 }
 
 fn page(input: &str) -> IResult<&str, Page> {
@@ -0,0 +1,176 @@
+use grid::{Grid, Offset};
+
+use crate::map::{Direction, Map, Position, Tile};
+
+/// End of a straight walk of the guard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    /// In front of an obstruction.
+    Obstruction(Position),
+    /// At the edge of the map, with the next step leaving it.
+    Edge(Position),
+}
+
+impl Stop {
+    pub fn position(self) -> Position {
+        match self {
+            Stop::Obstruction(position) | Stop::Edge(position) => position,
+        }
+    }
+}
+
+/// Where the guard stops when walking straight from any tile in any direction, so the walk
+/// can jump from one turn to the next instead of going tile by tile.
+#[derive(Debug, Clone)]
+pub struct JumpTable {
+    /// Position in front of the next obstruction, indexed by direction, or `None` if the guard
+    /// walks off the map.
+    stops: Grid<[Option<Position>; 4]>,
+}
+
+impl JumpTable {
+    pub fn new(map: &Map) -> Self {
+        let (width, height) = (map.width() as i64, map.height() as i64);
+        let positions = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Position::new(x, y)))
+            .collect::<Vec<_>>();
+
+        let mut stops = Grid::filled(map.width(), map.height(), [None; 4]);
+        for direction in Direction::ALL {
+            // Visit tiles so that the tile ahead of each one is always visited before it.
+            let reversed = matches!(direction, Direction::Down | Direction::Right);
+            for idx in 0..positions.len() {
+                let idx = if reversed {
+                    positions.len() - 1 - idx
+                } else {
+                    idx
+                };
+                let position = positions[idx];
+
+                let ahead = position.step(direction);
+                let stop = match map.get(ahead) {
+                    None => None,
+                    Some(Tile::Obstruction) => Some(position),
+                    Some(_) => stops.get(ahead).unwrap()[direction as usize],
+                };
+                stops.get_mut(position).unwrap()[direction as usize] = stop;
+            }
+        }
+
+        Self { stops }
+    }
+
+    /// Returns where the guard walking from `position` in `direction` stops, either in front
+    /// of an obstruction or at the edge of the map.
+    ///
+    /// The `extra_obstruction` is treated as if it was placed on the map, which is much cheaper
+    /// than building a new table for every added obstruction.
+    pub fn jump(
+        &self,
+        position: Position,
+        direction: Direction,
+        extra_obstruction: Option<Position>,
+    ) -> Stop {
+        let stop = self.stops.get(position).expect("Guard must be on the map")[direction as usize];
+        let step = Offset::from(direction);
+
+        if let Some(obstruction) = extra_obstruction {
+            let offset = obstruction - position;
+            let distance = offset.dx * step.dx + offset.dy * step.dy;
+            let stop_distance = stop.map(|stop| (stop - position).manhattan_length() as i64);
+
+            if distance > 0
+                && step * distance == offset
+                && stop_distance.is_none_or(|stop_distance| stop_distance >= distance)
+            {
+                return Stop::Obstruction(position + step * (distance - 1));
+            }
+        }
+
+        match stop {
+            Some(stop) => Stop::Obstruction(stop),
+            None => {
+                let (width, height) = (self.stops.width() as i64, self.stops.height() as i64);
+                let distance_to_edge = match direction {
+                    Direction::Up => position.y,
+                    Direction::Right => width - 1 - position.x,
+                    Direction::Down => height - 1 - position.y,
+                    Direction::Left => position.x,
+                };
+                Stop::Edge(position + step * distance_to_edge)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "..#.
+#...
+...#
+.^..";
+
+    #[test]
+    fn jump_to_obstruction() {
+        let map: Map = MAP.parse().unwrap();
+        let jumps = JumpTable::new(&map);
+
+        assert_eq!(
+            jumps.jump(Position::new(1, 3), Direction::Up, None),
+            Stop::Edge(Position::new(1, 0))
+        );
+        assert_eq!(
+            jumps.jump(Position::new(0, 3), Direction::Right, None),
+            Stop::Edge(Position::new(3, 3))
+        );
+        assert_eq!(
+            jumps.jump(Position::new(2, 3), Direction::Up, None),
+            Stop::Obstruction(Position::new(2, 1))
+        );
+        assert_eq!(
+            jumps.jump(Position::new(3, 1), Direction::Left, None),
+            Stop::Obstruction(Position::new(1, 1))
+        );
+        assert_eq!(
+            jumps.jump(Position::new(0, 2), Direction::Right, None),
+            Stop::Obstruction(Position::new(2, 2))
+        );
+        assert_eq!(
+            jumps.jump(Position::new(3, 0), Direction::Down, None),
+            Stop::Obstruction(Position::new(3, 1))
+        );
+    }
+
+    #[test]
+    fn jump_with_extra_obstruction() {
+        let map: Map = MAP.parse().unwrap();
+        let jumps = JumpTable::new(&map);
+        let start = Position::new(2, 3);
+
+        let jump = |obstruction| jumps.jump(start, Direction::Up, Some(obstruction));
+
+        assert_eq!(
+            jump(Position::new(2, 1)),
+            Stop::Obstruction(Position::new(2, 2))
+        );
+        assert_eq!(jump(Position::new(2, 2)), Stop::Obstruction(start));
+        assert_eq!(
+            jump(Position::new(2, 3)),
+            Stop::Obstruction(Position::new(2, 1))
+        );
+        assert_eq!(
+            jump(Position::new(3, 2)),
+            Stop::Obstruction(Position::new(2, 1))
+        );
+        assert_eq!(
+            jumps.jump(
+                Position::new(1, 3),
+                Direction::Up,
+                Some(Position::new(1, 0))
+            ),
+            Stop::Obstruction(Position::new(1, 1))
+        );
+    }
+}
@@ -1,76 +1,207 @@
-mod map;
+mod jump_table;
+pub mod map;
+pub mod path;
+pub mod patrol;
+pub mod rules;
+
+use std::{
+    collections::HashSet,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use common::{Answer, ParseError, Solution};
+use jump_table::JumpTable;
+use map::*;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use rules::Rules;
+
+/// Receives progress of a long running computation.
+pub trait Progress: Sync {
+    /// Called after each of `total` steps, possibly from multiple threads at once.
+    fn report(&self, done: usize, total: usize);
+}
 
-use std::collections::HashSet;
+impl<F: Fn(usize, usize) + Sync> Progress for F {
+    fn report(&self, done: usize, total: usize) {
+        self(done, total)
+    }
+}
 
-use map::*;
+/// Ignores all progress reports.
+pub struct NoProgress;
 
-fn get_all_visited_positions(map: &mut Map) -> HashSet<Position> {
-    let mut visited_positions = HashSet::new();
+impl Progress for NoProgress {
+    fn report(&self, _done: usize, _total: usize) {}
+}
+
+/// Walks the guard from `start` following `rules`, with `obstruction` added to the map.
+///
+/// The walk jumps between events, i.e. turns, wraps and reflections, calling `visit_segment`
+/// with the first and last position of every straight segment walked. Only states at events
+/// are recorded, which is enough to detect a loop, as every loop contains an event. Returns
+/// whether the guard got stuck in a loop.
+fn walk(
+    map: &Map,
+    jumps: &JumpTable,
+    rules: &Rules,
+    (mut position, mut direction): (Position, Direction),
+    obstruction: Option<Position>,
+    mut visit_segment: impl FnMut(Position, Position, Direction),
+) -> bool {
+    let is_blocked =
+        |position| map.get(position) == Some(Tile::Obstruction) || Some(position) == obstruction;
+    let mut event_states = HashSet::new();
+
+    loop {
+        let stop = jumps.jump(position, direction, obstruction);
+        visit_segment(position, stop.position(), direction);
+
+        if !event_states.insert((stop.position(), direction)) {
+            return true;
+        }
 
-    while let Some(guard_position) = map.guard_position() {
-        visited_positions.insert(guard_position);
-        map.tick()
+        match rules
+            .next_move(map, (stop.position(), direction), is_blocked)
+            .state()
+        {
+            Some(state) => (position, direction) = state,
+            None => return false,
+        }
     }
+}
+
+fn get_all_visited_positions(map: &Map, jumps: &JumpTable, rules: &Rules) -> HashSet<Position> {
+    let Some(guard) = map.guard() else {
+        return HashSet::new();
+    };
+
+    let mut visited_positions = HashSet::new();
+    walk(map, jumps, rules, guard, None, |start, end, direction| {
+        let mut position = start;
+        visited_positions.insert(position);
+        while position != end {
+            position = position.step(direction);
+            visited_positions.insert(position);
+        }
+    });
 
     visited_positions
 }
 
-pub fn solve_part_1(input: &str) -> usize {
-    let mut map: Map = input.parse().expect("Failed to parse puzzle input");
+/// Counts positions visited by the guard until it leaves the map or gets stuck in a loop.
+pub fn count_visited_positions_with_rules(map: &Map, rules: &Rules) -> usize {
+    get_all_visited_positions(map, &JumpTable::new(map), rules).len()
+}
 
-    get_all_visited_positions(&mut map).len()
+fn count_visited_positions(map: &Map) -> usize {
+    count_visited_positions_with_rules(map, &Rules::default())
 }
 
-fn check_guard_stuck_in_loop(map: &mut Map) -> bool {
-    let mut visited_states = HashSet::new();
+pub fn try_solve_part_1(input: &str) -> Result<usize, ParseError> {
+    let map: Map = input.parse()?;
 
-    while let Some(guard_position) = map.guard_position() {
-        let guard_direction = match map.get(guard_position) {
-            Some(Tile::Guard(direction)) => direction,
-            _ => unreachable!("Tile must be Guard at guard position"),
-        };
+    Ok(count_visited_positions(&map))
+}
 
-        let current_state = (guard_position, guard_direction);
-        if visited_states.contains(&current_state) {
-            return true;
-        }
+pub fn solve_part_1(input: &str) -> usize {
+    try_solve_part_1(input).expect("Failed to parse puzzle input")
+}
 
-        visited_states.insert(current_state);
-        map.tick();
-    }
+/// Counts positions where a single obstruction makes the guard walk in a loop.
+///
+/// Only positions visited without any obstruction are checked, as the guard never bumps into
+/// obstructions placed elsewhere. With the `parallel` feature enabled, these candidates are
+/// checked on all available cores.
+pub fn count_loop_obstructions_with_rules(
+    map: &Map,
+    rules: &Rules,
+    progress: &impl Progress,
+) -> usize {
+    let Some(guard) = map.guard() else {
+        return 0;
+    };
+
+    let jumps = JumpTable::new(map);
+    let candidates = get_all_visited_positions(map, &jumps, rules)
+        .into_iter()
+        .filter(|&position| map.get(position) == Some(Tile::Empty))
+        .collect::<Vec<_>>();
+
+    let num_candidates = candidates.len();
+    let num_done = AtomicUsize::new(0);
+    let causes_loop = |&&obstruction_position: &&Position| {
+        let stuck_in_loop = walk(
+            map,
+            &jumps,
+            rules,
+            guard,
+            Some(obstruction_position),
+            |_, _, _| {},
+        );
+
+        let done = num_done.fetch_add(1, Ordering::Relaxed) + 1;
+        progress.report(done, num_candidates);
+
+        stuck_in_loop
+    };
 
-    false
+    #[cfg(feature = "parallel")]
+    let result = candidates.par_iter().filter(causes_loop).count();
+    #[cfg(not(feature = "parallel"))]
+    let result = candidates.iter().filter(causes_loop).count();
+
+    result
 }
 
-pub fn solve_part_2(input: &str) -> usize {
-    let map: Map = input.parse().expect("Failed to parse puzzle input");
+fn count_loop_obstructions(map: &Map, progress: &impl Progress) -> usize {
+    count_loop_obstructions_with_rules(map, &Rules::default(), progress)
+}
 
-    let visited_positions_without_obstruction = {
-        let mut map = map.clone();
-        get_all_visited_positions(&mut map)
-    };
+pub fn try_solve_part_2_with_progress(
+    input: &str,
+    progress: &impl Progress,
+) -> Result<usize, ParseError> {
+    let map: Map = input.parse()?;
 
-    let num_positions = visited_positions_without_obstruction.len();
-    let mut result = 0;
-    for (i, obstruction_position) in visited_positions_without_obstruction
-        .into_iter()
-        .enumerate()
-    {
-        if map.get(obstruction_position).unwrap() != Tile::Empty {
-            continue;
-        }
+    Ok(count_loop_obstructions(&map, progress))
+}
 
-        let mut map = map.clone();
-        map.set(obstruction_position, Tile::Obstruction);
+pub fn try_solve_part_2(input: &str) -> Result<usize, ParseError> {
+    try_solve_part_2_with_progress(input, &NoProgress)
+}
 
-        if check_guard_stuck_in_loop(&mut map) {
-            result += 1;
-        }
+pub fn solve_part_2(input: &str) -> usize {
+    try_solve_part_2(input).expect("Failed to parse puzzle input")
+}
 
-        if i % 100 == 0 {
-            println!("{}% done", i * 100 / num_positions);
-        }
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+    const EXAMPLE: &'static str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
     }
 
-    result
+    fn part_1(map: &Self::Input) -> Answer {
+        count_visited_positions(map).into()
+    }
+
+    fn part_2(map: &Self::Input) -> Answer {
+        count_loop_obstructions(map, &NoProgress).into()
+    }
 }
@@ -1,30 +1,31 @@
-use std::fs;
-
+use common::{
+    input::{InputStore, REAL},
+    Solution,
+};
 use day06::*;
 
 fn main() {
-    let contents = fs::read_to_string("day06/input.txt")
+    let contents = InputStore::from_env()
+        .and_then(|store| store.load(Day06::DAY, REAL))
         .expect("Failed to read puzzle input");
 
     println!("Part 1: {}", solve_part_1(&contents));
 
-    println!("Part 2: {}", solve_part_2(&contents));
+    let report_progress = |done: usize, total: usize| {
+        if done.is_multiple_of(100) || done == total {
+            eprintln!("{}% done", done * 100 / total);
+        }
+    };
+    let sol = try_solve_part_2_with_progress(&contents, &report_progress)
+        .expect("Failed to parse puzzle input");
+    println!("Part 2: {sol}");
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...";
+    const INPUT: &str = Day06::EXAMPLE;
 
     #[test]
     fn test_part_1() {
@@ -39,4 +40,21 @@ mod tests {
 
         assert_eq!(sol, 6);
     }
+
+    #[test]
+    fn test_part_2_progress() {
+        let reports = std::sync::Mutex::new(Vec::new());
+        let progress = |done, total| reports.lock().unwrap().push((done, total));
+
+        let sol = try_solve_part_2_with_progress(INPUT, &progress).unwrap();
+        let mut reports = reports.into_inner().unwrap();
+        reports.sort();
+
+        assert_eq!(sol, 6);
+        assert_eq!(reports.len(), 40);
+        assert!(reports
+            .iter()
+            .enumerate()
+            .all(|(i, &report)| report == (i + 1, 40)));
+    }
 }
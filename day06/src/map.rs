@@ -1,45 +1,8 @@
-use std::{convert::Infallible, str::FromStr};
+use std::{fmt, str::FromStr};
 
-use itertools::Itertools;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Position {
-    pub x: i64,
-    pub y: i64,
-}
-
-impl Position {
-    pub fn step(self, direction: Direction) -> Self {
-        use Direction::*;
-        let (x, y) = match direction {
-            Up => (self.x, self.y - 1),
-            Down => (self.x, self.y + 1),
-            Left => (self.x - 1, self.y),
-            Right => (self.x + 1, self.y),
-        };
-        Position { x, y }
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    pub fn rotate_clockwise(self) -> Self {
-        use Direction::*;
-        match self {
-            Up => Right,
-            Right => Down,
-            Down => Left,
-            Left => Up,
-        }
-    }
-}
+use common::ParseError;
+use grid::Grid;
+pub use grid::{Direction, Position};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Tile {
@@ -48,110 +11,108 @@ pub enum Tile {
     Guard(Direction),
 }
 
-impl From<char> for Tile {
-    fn from(char: char) -> Self {
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(char: char) -> Result<Self, Self::Error> {
         use Tile::*;
 
         match char {
-            '.' => Empty,
-            '#' => Obstruction,
-            '^' => Guard(Direction::Up),
-            _ => panic!("Encountered invalid tile"),
+            '.' => Ok(Empty),
+            '#' => Ok(Obstruction),
+            '^' => Ok(Guard(Direction::Up)),
+            '>' => Ok(Guard(Direction::Right)),
+            'v' => Ok(Guard(Direction::Down)),
+            '<' => Ok(Guard(Direction::Left)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Tile::Empty => '.',
+            Tile::Obstruction => '#',
+            Tile::Guard(Direction::Up) => '^',
+            Tile::Guard(Direction::Right) => '>',
+            Tile::Guard(Direction::Down) => 'v',
+            Tile::Guard(Direction::Left) => '<',
         }
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct Map {
-    tiles: Vec<Tile>,
-    pub width: usize,
-    pub height: usize,
+    tiles: Grid<Tile>,
 }
 
 impl Map {
-    fn check_bounds(&self, position: Position) -> bool {
-        (0..self.width as i64).contains(&position.x)
-            && (0..self.height as i64).contains(&position.y)
+    pub fn width(&self) -> usize {
+        self.tiles.width()
     }
 
-    pub fn get(&self, position: Position) -> Option<Tile> {
-        if !self.check_bounds(position) {
-            return None;
-        }
-
-        let idx = position.x + position.y * self.width as i64;
-        self.tiles.get(idx as usize).copied()
+    pub fn height(&self) -> usize {
+        self.tiles.height()
     }
 
-    pub fn set(&mut self, position: Position, tile: Tile) {
-        if !self.check_bounds(position) {
-            return;
-        }
-
-        let idx = position.x + position.y * self.width as i64;
-        self.tiles[idx as usize] = tile;
+    /// Wraps position around the edges, as if the map was a torus.
+    pub fn wrap(&self, position: Position) -> Position {
+        Position::new(
+            position.x.rem_euclid(self.width() as i64),
+            position.y.rem_euclid(self.height() as i64),
+        )
     }
 
-    pub fn guard_position(&self) -> Option<Position> {
-        let idx = self
-            .tiles
-            .iter()
-            .position(|&tile| matches!(tile, Tile::Guard(_)))?;
+    pub fn get(&self, position: Position) -> Option<Tile> {
+        self.tiles.get(position).copied()
+    }
 
-        let x = (idx % self.width) as i64;
-        let y = (idx / self.width) as i64;
+    pub fn set(&mut self, position: Position, tile: Tile) {
+        self.tiles.set(position, tile);
+    }
 
-        Some(Position { x, y })
+    /// Returns position and direction of the guard, the first one row by row if there are more.
+    pub fn guard(&self) -> Option<(Position, Direction)> {
+        self.guards().next()
     }
 
-    pub fn tick(&mut self) {
-        let Some(guard_position) = self.guard_position() else {
-            return;
-        };
-
-        let guard_tile = self.get(guard_position).unwrap();
-        let guard_direction = match guard_tile {
-            Tile::Guard(direction) => direction,
-            _ => unreachable!("Tile must be Guard at guard position"),
-        };
-
-        let next_guard_position = guard_position.step(guard_direction);
-        let obstruction_ahead = self.get(next_guard_position) == Some(Tile::Obstruction);
-        if obstruction_ahead {
-            let new_direction = guard_direction.rotate_clockwise();
-            let new_tile = Tile::Guard(new_direction);
-            self.set(guard_position, new_tile);
-        } else {
-            self.set(next_guard_position, guard_tile);
-            self.set(guard_position, Tile::Empty);
-        }
+    /// Returns positions and directions of all guards, row by row.
+    pub fn guards(&self) -> impl Iterator<Item = (Position, Direction)> + '_ {
+        self.tiles
+            .iter()
+            .filter_map(|(position, &tile)| match tile {
+                Tile::Guard(direction) => Some((position, direction)),
+                _ => None,
+            })
     }
 }
 
 impl FromStr for Map {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let lines = string.lines().collect_vec();
-        let height = lines.len();
-        let width = lines[0].len();
-
-        let tiles = lines
-            .into_iter()
-            .flat_map(str::chars)
-            .map(|char| char.into())
-            .collect_vec();
-
-        assert_eq!(tiles.len(), width * height);
-
         Ok(Self {
-            tiles,
-            width,
-            height,
+            tiles: string.parse()?,
         })
     }
 }
 
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.tiles.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for &tile in row {
+                write!(f, "{}", char::from(tile))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,10 +125,54 @@ mod tests {
 
         let map: Map = input.parse().unwrap();
 
-        assert_eq!(map.width, 3);
-        assert_eq!(map.height, 3);
-        assert_eq!(map.tiles[0], Tile::Empty);
-        assert_eq!(map.tiles[3], Tile::Obstruction);
-        assert_eq!(map.tiles[4], Tile::Guard(Direction::Up));
+        assert_eq!(map.tiles.width(), 3);
+        assert_eq!(map.tiles.height(), 3);
+        assert_eq!(map.tiles.cells()[0], Tile::Empty);
+        assert_eq!(map.tiles.cells()[3], Tile::Obstruction);
+        assert_eq!(map.tiles.cells()[4], Tile::Guard(Direction::Up));
+    }
+
+    #[test]
+    fn parse_map_multiple_guards() {
+        let input = ">.#
+#^.
+.<v";
+
+        let map: Map = input.parse().unwrap();
+
+        assert_eq!(map.guard(), Some((Position::new(0, 0), Direction::Right)));
+        assert_eq!(
+            map.guards().collect::<Vec<_>>(),
+            [
+                (Position::new(0, 0), Direction::Right),
+                (Position::new(1, 1), Direction::Up),
+                (Position::new(1, 2), Direction::Left),
+                (Position::new(2, 2), Direction::Down),
+            ]
+        );
+        assert_eq!(map.to_string(), input);
+    }
+
+    #[test]
+    fn display_map() {
+        let input = ".#.
+#^.
+..#";
+
+        let map: Map = input.parse().unwrap();
+
+        assert_eq!(map.to_string(), input);
+    }
+
+    #[test]
+    fn parse_map_invalid_tile() {
+        let input = "..#
+.?.";
+
+        let error = input.parse::<Map>().unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 2);
+        assert_eq!(error.text, "?.");
     }
 }
@@ -0,0 +1,183 @@
+use std::{collections::HashSet, fmt};
+
+use crate::{
+    map::{Direction, Map, Position, Tile},
+    rules::{Move, Rules},
+};
+
+/// Single state of the guard, i.e. where it stands and which way it faces.
+pub type GuardState = (Position, Direction);
+
+/// Complete walk of the guard, traced tile by tile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardPath {
+    /// Obstruction added to the map before the walk.
+    pub obstruction: Option<Position>,
+    /// All states of the guard in order, starting with the initial one.
+    pub states: Vec<GuardState>,
+    /// States in which the guard faced an obstruction and turned, in order.
+    pub turns: Vec<GuardState>,
+    /// State in which the guard got back to an already visited state, closing a loop.
+    /// `None` if the guard walked off the map.
+    pub loop_closure: Option<GuardState>,
+}
+
+impl GuardPath {
+    /// Returns rendering of the map with the path overlaid on top of it, as in the puzzle.
+    pub fn render<'a>(&'a self, map: &'a Map) -> PathDisplay<'a> {
+        PathDisplay { map, path: self }
+    }
+}
+
+/// Traces walk of the guard following `rules` until it leaves the map or gets stuck in a loop,
+/// optionally with an additional `obstruction` placed on the map.
+pub fn trace_path(map: &Map, rules: &Rules, obstruction: Option<Position>) -> GuardPath {
+    let mut path = GuardPath {
+        obstruction,
+        states: Vec::new(),
+        turns: Vec::new(),
+        loop_closure: None,
+    };
+    let Some(mut state) = map.guard() else {
+        return path;
+    };
+
+    let is_blocked =
+        |position| map.get(position) == Some(Tile::Obstruction) || Some(position) == obstruction;
+    let mut visited_states = HashSet::new();
+    loop {
+        if !visited_states.insert(state) {
+            path.loop_closure = Some(state);
+            return path;
+        }
+        path.states.push(state);
+
+        let next_move = rules.next_move(map, state, is_blocked);
+        if let Move::Turn(_) = next_move {
+            path.turns.push(state);
+        }
+        match next_move.state() {
+            Some(next_state) => state = next_state,
+            None => return path,
+        }
+    }
+}
+
+/// Map with the guard path drawn with `|`, `-` and `+`, and the added obstruction as `O`.
+pub struct PathDisplay<'a> {
+    map: &'a Map,
+    path: &'a GuardPath,
+}
+
+impl fmt::Display for PathDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut vertical = HashSet::new();
+        let mut horizontal = HashSet::new();
+        for &(position, direction) in &self.path.states {
+            match direction {
+                Direction::Up | Direction::Down => vertical.insert(position),
+                Direction::Left | Direction::Right => horizontal.insert(position),
+            };
+        }
+
+        for y in 0..self.map.height() as i64 {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for x in 0..self.map.width() as i64 {
+                let position = Position::new(x, y);
+                let tile = self.map.get(position).unwrap();
+                let char = if Some(position) == self.path.obstruction {
+                    'O'
+                } else if tile != Tile::Empty {
+                    tile.into()
+                } else {
+                    match (vertical.contains(&position), horizontal.contains(&position)) {
+                        (true, true) => '+',
+                        (true, false) => '|',
+                        (false, true) => '-',
+                        (false, false) => '.',
+                    }
+                };
+                write!(f, "{char}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Day06;
+    use common::Solution;
+
+    #[test]
+    fn trace_path_without_loop() {
+        let map: Map = Day06::EXAMPLE.parse().unwrap();
+
+        let path = trace_path(&map, &Rules::default(), None);
+
+        assert_eq!(path.loop_closure, None);
+        assert_eq!(
+            path.states.first(),
+            Some(&(Position::new(4, 6), Direction::Up))
+        );
+        assert_eq!(
+            path.states.last(),
+            Some(&(Position::new(7, 9), Direction::Down))
+        );
+        assert_eq!(path.turns[0], (Position::new(4, 1), Direction::Up));
+        assert_eq!(path.turns[1], (Position::new(8, 1), Direction::Right));
+    }
+
+    #[test]
+    fn trace_path_into_loop() {
+        let map: Map = Day06::EXAMPLE.parse().unwrap();
+
+        let path = trace_path(&map, &Rules::default(), Some(Position::new(3, 6)));
+
+        assert_eq!(
+            path.loop_closure,
+            Some((Position::new(4, 6), Direction::Up))
+        );
+        assert_eq!(path.turns.len(), 4);
+        assert_eq!(
+            path.render(&map).to_string(),
+            "....#.....
+....+---+#
+....|...|.
+..#.|...|.
+....|..#|.
+....|...|.
+.#.O^---+.
+........#.
+#.........
+......#..."
+        );
+    }
+
+    #[test]
+    fn render_path_crossings() {
+        let map: Map = Day06::EXAMPLE.parse().unwrap();
+
+        let path = trace_path(&map, &Rules::default(), Some(Position::new(6, 7)));
+
+        assert!(path.loop_closure.is_some());
+        assert_eq!(
+            path.render(&map).to_string(),
+            "....#.....
+....+---+#
+....|...|.
+..#.|...|.
+..+-+-+#|.
+..|.|.|.|.
+.#+-^-+-+.
+......O.#.
+#.........
+......#..."
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::HashSet;
+
+use crate::{
+    map::{Map, Position, Tile},
+    path::GuardState,
+    rules::Rules,
+};
+
+/// Options of a [`Patrol`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PatrolOptions {
+    /// Whether guards turn in front of other guards, as if they were obstructions.
+    pub guards_block_each_other: bool,
+}
+
+/// All guards of a map, walking in lockstep.
+#[derive(Debug, Clone)]
+pub struct Patrol<'a> {
+    map: &'a Map,
+    rules: &'a Rules,
+    options: PatrolOptions,
+    /// States of guards in the order they appear on the map, `None` once a guard leaves it.
+    guards: Vec<Option<GuardState>>,
+}
+
+impl<'a> Patrol<'a> {
+    pub fn new(map: &'a Map, rules: &'a Rules, options: PatrolOptions) -> Self {
+        Self {
+            map,
+            rules,
+            options,
+            guards: map.guards().map(Some).collect(),
+        }
+    }
+
+    pub fn guards(&self) -> &[Option<GuardState>] {
+        &self.guards
+    }
+
+    /// Returns whether all guards have left the map.
+    pub fn is_over(&self) -> bool {
+        self.guards.iter().all(Option::is_none)
+    }
+
+    fn is_blocked(&self, position: Position) -> bool {
+        match self.map.get(position) {
+            Some(Tile::Obstruction) => true,
+            _ => {
+                self.options.guards_block_each_other
+                    && self
+                        .guards
+                        .iter()
+                        .flatten()
+                        .any(|&(guard_position, _)| guard_position == position)
+            }
+        }
+    }
+
+    /// Makes every guard either turn or take a step.
+    ///
+    /// Guards move one after another in the order they appear on the map, so when they block
+    /// each other, a guard sees guards before it already in their new positions.
+    pub fn tick(&mut self) {
+        self.advance();
+    }
+
+    /// Makes a tick and returns whether any guard turned, wrapped or bounced off an edge in it.
+    fn advance(&mut self) -> bool {
+        let mut any_event = false;
+        for idx in 0..self.guards.len() {
+            let Some(state) = self.guards[idx] else {
+                continue;
+            };
+
+            let next_move = self
+                .rules
+                .next_move(self.map, state, |position| self.is_blocked(position));
+            any_event |= next_move.is_event();
+            self.guards[idx] = next_move.state();
+        }
+
+        any_event
+    }
+
+    /// Walks until all guards leave the map and returns positions visited by any of them,
+    /// or `None` if the guards get stuck in a loop.
+    pub fn visited_positions(mut self) -> Option<HashSet<Position>> {
+        let mut visited_positions = HashSet::new();
+        // Without events guards only walk straight until they leave the map, so every loop
+        // contains an event and it's enough to record states right after events.
+        let mut event_states = HashSet::new();
+
+        while !self.is_over() {
+            visited_positions.extend(self.guards.iter().flatten().map(|&(position, _)| position));
+
+            if self.advance() && !event_states.insert(self.guards.clone()) {
+                return None;
+            }
+        }
+
+        Some(visited_positions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{map::Direction, Day06};
+    use common::Solution;
+
+    #[test]
+    fn patrol_single_guard() {
+        let map: Map = Day06::EXAMPLE.parse().unwrap();
+        let rules = Rules::default();
+
+        let patrol = Patrol::new(&map, &rules, PatrolOptions::default());
+
+        assert_eq!(
+            patrol.visited_positions().map(|positions| positions.len()),
+            Some(41)
+        );
+    }
+
+    #[test]
+    fn patrol_guards_pass_each_other() {
+        let map: Map = "....
+>..<
+...."
+            .parse()
+            .unwrap();
+        let rules = Rules::default();
+
+        let mut patrol = Patrol::new(&map, &rules, PatrolOptions::default());
+        patrol.tick();
+        patrol.tick();
+
+        assert_eq!(
+            patrol.guards(),
+            [
+                Some((Position::new(2, 1), Direction::Right)),
+                Some((Position::new(1, 1), Direction::Left)),
+            ]
+        );
+        assert_eq!(
+            patrol.visited_positions().map(|positions| positions.len()),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn patrol_guards_block_each_other() {
+        let map: Map = ".....
+>...<
+....."
+            .parse()
+            .unwrap();
+        let rules = Rules::default();
+        let options = PatrolOptions {
+            guards_block_each_other: true,
+        };
+
+        let mut patrol = Patrol::new(&map, &rules, options);
+        patrol.tick();
+        patrol.tick();
+
+        assert_eq!(
+            patrol.guards(),
+            [
+                Some((Position::new(2, 1), Direction::Right)),
+                Some((Position::new(3, 1), Direction::Up)),
+            ]
+        );
+
+        patrol.tick();
+        patrol.tick();
+
+        assert_eq!(
+            patrol.guards(),
+            [Some((Position::new(2, 2), Direction::Down)), None]
+        );
+        assert!(Patrol::new(&map, &rules, options)
+            .visited_positions()
+            .is_some());
+    }
+
+    #[test]
+    fn patrol_guards_stuck_in_loop() {
+        let map: Map = ".#..
+...#
+#^..
+..#."
+            .parse()
+            .unwrap();
+        let rules = Rules::default();
+
+        let patrol = Patrol::new(&map, &rules, PatrolOptions::default());
+
+        assert_eq!(patrol.visited_positions(), None);
+    }
+}
@@ -0,0 +1,282 @@
+use std::{fmt, sync::Arc};
+
+use crate::{
+    map::{Direction, Map, Position},
+    path::GuardState,
+};
+
+/// Which way the guard turns when facing an obstruction.
+#[derive(Clone, Default)]
+pub enum TurnPolicy {
+    /// Turns right by 90 degrees, as in the puzzle.
+    #[default]
+    Right,
+    /// Turns left by 90 degrees.
+    Left,
+    /// Turns back.
+    Reverse,
+    /// Turns to the direction returned by the function.
+    Custom(Arc<dyn Fn(Direction) -> Direction + Send + Sync>),
+}
+
+impl TurnPolicy {
+    pub fn custom(turn: impl Fn(Direction) -> Direction + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(turn))
+    }
+
+    pub fn turn(&self, direction: Direction) -> Direction {
+        match self {
+            Self::Right => direction.rotate_clockwise(),
+            Self::Left => direction.rotate_counter_clockwise(),
+            Self::Reverse => direction.opposite(),
+            Self::Custom(turn) => turn(direction),
+        }
+    }
+}
+
+impl fmt::Debug for TurnPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Right => write!(f, "Right"),
+            Self::Left => write!(f, "Left"),
+            Self::Reverse => write!(f, "Reverse"),
+            Self::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+/// What happens when the guard steps off the map.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EdgeMode {
+    /// The guard leaves and the walk ends, as in the puzzle.
+    #[default]
+    Exit,
+    /// The guard enters the map again from the opposite edge, as if it was a torus.
+    Wrap,
+    /// The guard bounces off the edge and turns back.
+    Reflect,
+}
+
+/// Rules of the guard movement, which by default are the ones from the puzzle.
+#[derive(Debug, Clone, Default)]
+pub struct Rules {
+    pub turn: TurnPolicy,
+    pub edge: EdgeMode,
+}
+
+/// Single move of the guard, together with its new state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    /// Steps forward to the next tile.
+    Step(GuardState),
+    /// Turns in front of an obstruction.
+    Turn(GuardState),
+    /// Steps off the map and enters it again from the opposite edge.
+    Wrap(GuardState),
+    /// Bounces off the edge of the map.
+    Reflect(GuardState),
+    /// Leaves the map.
+    Exit,
+}
+
+impl Move {
+    /// Returns new state of the guard, or `None` if it left the map.
+    pub fn state(self) -> Option<GuardState> {
+        match self {
+            Move::Step(state) | Move::Turn(state) | Move::Wrap(state) | Move::Reflect(state) => {
+                Some(state)
+            }
+            Move::Exit => None,
+        }
+    }
+
+    /// Returns whether the guard did anything else than walking straight on, so that every
+    /// loop contains at least one such event.
+    pub fn is_event(self) -> bool {
+        !matches!(self, Move::Step(_))
+    }
+}
+
+impl Rules {
+    /// Moves the guard by a single tile or turns it, with `is_blocked` telling which positions
+    /// on the map the guard can't enter.
+    pub fn next_move(
+        &self,
+        map: &Map,
+        (position, direction): GuardState,
+        is_blocked: impl Fn(Position) -> bool,
+    ) -> Move {
+        let mut next_position = position.step(direction);
+        let mut wrapped = false;
+        if map.get(next_position).is_none() {
+            match self.edge {
+                EdgeMode::Exit => return Move::Exit,
+                EdgeMode::Reflect => return Move::Reflect((position, direction.opposite())),
+                EdgeMode::Wrap => {
+                    next_position = map.wrap(next_position);
+                    wrapped = true;
+                }
+            }
+        }
+
+        if is_blocked(next_position) {
+            Move::Turn((position, self.turn.turn(direction)))
+        } else if wrapped {
+            Move::Wrap((next_position, direction))
+        } else {
+            Move::Step((next_position, direction))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{
+        count_loop_obstructions_with_rules, count_visited_positions_with_rules,
+        map::{Map, Position, Tile},
+        path::trace_path,
+        patrol::{Patrol, PatrolOptions},
+        Day06, NoProgress,
+    };
+    use common::Solution;
+
+    /// Walks tile by tile, returning visited positions and whether the guard got stuck in a loop.
+    fn walk_naive(
+        map: &Map,
+        rules: &Rules,
+        obstruction: Option<Position>,
+    ) -> (HashSet<Position>, bool) {
+        let (mut position, mut direction) = map.guard().unwrap();
+        let mut visited_states = HashSet::new();
+
+        while visited_states.insert((position, direction)) {
+            let mut next_position = position.step(direction);
+            if map.get(next_position).is_none() {
+                match rules.edge {
+                    EdgeMode::Exit => return (positions(&visited_states), false),
+                    EdgeMode::Wrap => next_position = map.wrap(next_position),
+                    EdgeMode::Reflect => {
+                        direction = direction.opposite();
+                        continue;
+                    }
+                }
+            }
+
+            if map.get(next_position) == Some(Tile::Obstruction)
+                || Some(next_position) == obstruction
+            {
+                direction = rules.turn.turn(direction);
+            } else {
+                position = next_position;
+            }
+        }
+
+        (positions(&visited_states), true)
+    }
+
+    fn positions(states: &HashSet<(Position, Direction)>) -> HashSet<Position> {
+        states.iter().map(|&(position, _)| position).collect()
+    }
+
+    fn all_rules() -> Vec<Rules> {
+        let turns = [
+            TurnPolicy::Right,
+            TurnPolicy::Left,
+            TurnPolicy::Reverse,
+            TurnPolicy::custom(|direction| match direction {
+                Direction::Up | Direction::Down => Direction::Left,
+                Direction::Left | Direction::Right => Direction::Down,
+            }),
+        ];
+        let edges = [EdgeMode::Exit, EdgeMode::Wrap, EdgeMode::Reflect];
+
+        turns
+            .iter()
+            .flat_map(|turn| {
+                edges.map(|edge| Rules {
+                    turn: turn.clone(),
+                    edge,
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn turn_policies() {
+        assert_eq!(TurnPolicy::Right.turn(Direction::Up), Direction::Right);
+        assert_eq!(TurnPolicy::Left.turn(Direction::Up), Direction::Left);
+        assert_eq!(TurnPolicy::Reverse.turn(Direction::Up), Direction::Down);
+        assert_eq!(
+            TurnPolicy::custom(|_| Direction::Left).turn(Direction::Up),
+            Direction::Left
+        );
+    }
+
+    #[test]
+    fn puzzle_rules_by_default() {
+        let map: Map = Day06::EXAMPLE.parse().unwrap();
+
+        assert_eq!(
+            count_visited_positions_with_rules(&map, &Rules::default()),
+            41
+        );
+        assert_eq!(
+            count_loop_obstructions_with_rules(&map, &Rules::default(), &NoProgress),
+            6
+        );
+    }
+
+    #[test]
+    fn traced_and_patrolled_paths_follow_rules() {
+        let map: Map = Day06::EXAMPLE.parse().unwrap();
+
+        for rules in all_rules() {
+            for obstruction in [None, Some(Position::new(3, 6))] {
+                let (visited_positions, stuck_in_loop) = walk_naive(&map, &rules, obstruction);
+
+                let path = trace_path(&map, &rules, obstruction);
+                let traced_positions = positions(&path.states.iter().copied().collect());
+
+                assert_eq!(traced_positions, visited_positions, "{rules:?}");
+                assert_eq!(path.loop_closure.is_some(), stuck_in_loop, "{rules:?}");
+            }
+
+            let (visited_positions, stuck_in_loop) = walk_naive(&map, &rules, None);
+            let patrol = Patrol::new(&map, &rules, PatrolOptions::default());
+
+            assert_eq!(
+                patrol.visited_positions(),
+                (!stuck_in_loop).then_some(visited_positions),
+                "{rules:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn rules_match_naive_walk() {
+        let map: Map = Day06::EXAMPLE.parse().unwrap();
+
+        for rules in all_rules() {
+            let (visited_positions, _) = walk_naive(&map, &rules, None);
+            let num_loops = visited_positions
+                .iter()
+                .filter(|&&position| map.get(position) == Some(Tile::Empty))
+                .filter(|&&obstruction| walk_naive(&map, &rules, Some(obstruction)).1)
+                .count();
+
+            assert_eq!(
+                count_visited_positions_with_rules(&map, &rules),
+                visited_positions.len(),
+                "{rules:?}"
+            );
+            assert_eq!(
+                count_loop_obstructions_with_rules(&map, &rules, &NoProgress),
+                num_loops,
+                "{rules:?}"
+            );
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use callibration::{Equation, Operator};
+use common::{Answer, ParseError, Solution};
 use parse::parse_input;
 
 mod callibration;
 mod parse;
 
-fn try_solve_equation(equation: Equation, operations: &[Operator]) -> Option<i64> {
+fn try_solve_equation(equation: &Equation, operations: &[Operator]) -> Option<i64> {
     fn bruteforce(
         operations: &[Operator],
         accumulator: i64,
@@ -30,25 +31,69 @@ fn try_solve_equation(equation: Equation, operations: &[Operator]) -> Option<i64
     .then_some(equation.test_value)
 }
 
-pub fn solve_part_1(input: &str) -> i64 {
-    let equations = parse_input(input);
-
+fn total_calibration_result(equations: &[Equation], operations: &[Operator]) -> i64 {
     equations
-        .into_iter()
-        .filter_map(|eq| try_solve_equation(eq, &[Operator::Add, Operator::Multiply]))
+        .iter()
+        .filter_map(|eq| try_solve_equation(eq, operations))
         .sum()
 }
 
+pub fn try_solve_part_1(input: &str) -> Result<i64, ParseError> {
+    let equations = parse_input(input)?;
+
+    Ok(total_calibration_result(
+        &equations,
+        &[Operator::Add, Operator::Multiply],
+    ))
+}
+
+pub fn solve_part_1(input: &str) -> i64 {
+    try_solve_part_1(input).expect("Failed to parse puzzle input")
+}
+
+pub fn try_solve_part_2(input: &str) -> Result<i64, ParseError> {
+    let equations = parse_input(input)?;
+
+    Ok(total_calibration_result(
+        &equations,
+        &[Operator::Add, Operator::Multiply, Operator::Concatenate],
+    ))
+}
+
 pub fn solve_part_2(input: &str) -> i64 {
-    let equations = parse_input(input);
+    try_solve_part_2(input).expect("Failed to parse puzzle input")
+}
 
-    equations
-        .into_iter()
-        .filter_map(|eq| {
-            try_solve_equation(
-                eq,
-                &[Operator::Add, Operator::Multiply, Operator::Concatenate],
-            )
-        })
-        .sum()
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Bridge Repair";
+    const EXAMPLE: &'static str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(equations: &Self::Input) -> Answer {
+        total_calibration_result(equations, &[Operator::Add, Operator::Multiply]).into()
+    }
+
+    fn part_2(equations: &Self::Input) -> Answer {
+        total_calibration_result(
+            equations,
+            &[Operator::Add, Operator::Multiply, Operator::Concatenate],
+        )
+        .into()
+    }
 }
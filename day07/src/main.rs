@@ -1,9 +1,12 @@
-use std::fs;
-
+use common::{
+    input::{InputStore, REAL},
+    Solution,
+};
 use day07::*;
 
 fn main() {
-    let contents = fs::read_to_string("day07/input.txt")
+    let contents = InputStore::from_env()
+        .and_then(|store| store.load(Day07::DAY, REAL))
         .expect("Failed to read puzzle input");
 
     println!("Part 1: {}", solve_part_1(&contents));
@@ -15,15 +18,7 @@ fn main() {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20";
+    const INPUT: &str = Day07::EXAMPLE;
 
     #[test]
     fn test_part_1() {
@@ -1,16 +1,12 @@
+use common::{parse::parse_all, ParseError};
 use nom::{
-    bytes::complete::tag, character::complete::*, combinator::*, multi::*, sequence::*, Finish,
-    IResult,
+    bytes::complete::tag, character::complete::*, combinator::*, multi::*, sequence::*, IResult,
 };
 
 use crate::callibration::Equation;
 
-pub fn parse_input(input: &str) -> Vec<Equation> {
-    let (_, equations) = equation_list(input)
-        .finish()
-        .expect("Failed to parse puzzle input");
-
-    equations
+pub fn parse_input(input: &str) -> Result<Vec<Equation>, ParseError> {
+    parse_all(input, equation_list)
 }
 
 fn number(input: &str) -> IResult<&str, i64> {
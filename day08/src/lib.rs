@@ -1,5 +1,6 @@
 use std::collections::HashSet;
 
+use common::{Answer, ParseError, Solution};
 use itertools::Itertools;
 use map::{Map, Position};
 
@@ -7,15 +8,14 @@ mod map;
 
 fn determine_antinodes(map: &Map) -> HashSet<Position> {
     let grouped_antennae = map
-        .antennae
-        .iter()
+        .antennae()
         .map(|(position, antenna)| (antenna, position))
         .into_group_map();
 
     let mut antinodes = HashSet::new();
 
     for (_antenna, positions) in grouped_antennae {
-        for (&pos1, &pos2) in positions.into_iter().tuple_combinations() {
+        for (pos1, pos2) in positions.into_iter().tuple_combinations() {
             let offset = pos2 - pos1;
             for antinode_pos in [pos1 - offset, pos2 + offset] {
                 if map.bounds_check(antinode_pos) {
@@ -28,35 +28,38 @@ fn determine_antinodes(map: &Map) -> HashSet<Position> {
     antinodes
 }
 
-pub fn solve_part_1(input: &str) -> usize {
-    let map: Map = input.parse().expect("Failed to parse puzzle input");
+pub fn try_solve_part_1(input: &str) -> Result<usize, ParseError> {
+    let map: Map = input.parse()?;
+
+    Ok(determine_antinodes(&map).len())
+}
 
-    determine_antinodes(&map).len()
+pub fn solve_part_1(input: &str) -> usize {
+    try_solve_part_1(input).expect("Failed to parse puzzle input")
 }
 
 fn determine_t_antinodes(map: &Map) -> HashSet<Position> {
     let grouped_antennae = map
-        .antennae
-        .iter()
+        .antennae()
         .map(|(position, antenna)| (antenna, position))
         .into_group_map();
 
     let mut antinodes = HashSet::new();
 
     for (_antenna, positions) in grouped_antennae {
-        for (&pos1, &pos2) in positions.into_iter().tuple_combinations() {
+        for (pos1, pos2) in positions.into_iter().tuple_combinations() {
             let offset = pos2 - pos1;
 
             let mut antinode = pos1;
             while map.bounds_check(antinode) {
                 antinodes.insert(antinode);
-                antinode = antinode - offset;
+                antinode -= offset;
             }
 
             antinode = pos2;
             while map.bounds_check(antinode) {
                 antinodes.insert(antinode);
-                antinode = antinode + offset;
+                antinode += offset;
             }
         }
     }
@@ -64,8 +67,45 @@ fn determine_t_antinodes(map: &Map) -> HashSet<Position> {
     antinodes
 }
 
+pub fn try_solve_part_2(input: &str) -> Result<usize, ParseError> {
+    let map: Map = input.parse()?;
+
+    Ok(determine_t_antinodes(&map).len())
+}
+
 pub fn solve_part_2(input: &str) -> usize {
-    let map: Map = input.parse().expect("Failed to parse puzzle input");
+    try_solve_part_2(input).expect("Failed to parse puzzle input")
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Resonant Collinearity";
+    const EXAMPLE: &'static str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
 
-    determine_t_antinodes(&map).len()
+    fn part_1(map: &Self::Input) -> Answer {
+        determine_antinodes(map).len().into()
+    }
+
+    fn part_2(map: &Self::Input) -> Answer {
+        determine_t_antinodes(map).len().into()
+    }
 }
@@ -1,9 +1,12 @@
-use std::fs;
-
+use common::{
+    input::{InputStore, REAL},
+    Solution,
+};
 use day08::*;
 
 fn main() {
-    let contents = fs::read_to_string("day08/input.txt")
+    let contents = InputStore::from_env()
+        .and_then(|store| store.load(Day08::DAY, REAL))
         .expect("Failed to read puzzle input");
 
     println!("Part 1: {}", solve_part_1(&contents));
@@ -15,18 +18,7 @@ fn main() {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "............
-........0...
-.....0......
-.......0....
-....0.......
-......A.....
-............
-............
-........A...
-.........A..
-............
-............";
+    const INPUT: &str = Day08::EXAMPLE;
 
     #[test]
     fn test_part_1() {
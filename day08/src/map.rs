@@ -1,50 +1,8 @@
-use std::{
-    collections::HashMap,
-    convert::Infallible,
-    ops::{Add, Neg, Sub},
-    str::FromStr,
-};
+use std::str::FromStr;
 
-use itertools::Itertools;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Position {
-    x: i64,
-    y: i64,
-}
-
-impl Add for Position {
-    type Output = Self;
-
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
-
-impl Sub for Position {
-    type Output = Self;
-
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
-    }
-}
-
-impl Neg for Position {
-    type Output = Self;
-
-    fn neg(self) -> Self::Output {
-        Self {
-            x: -self.x,
-            y: -self.y,
-        }
-    }
-}
+use common::ParseError;
+use grid::Grid;
+pub use grid::Position;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Antenna(char);
@@ -57,57 +15,39 @@ impl From<char> for Antenna {
 
 #[derive(Debug)]
 pub struct Map {
-    pub width: u64,
-    pub height: u64,
-    pub antennae: HashMap<Position, Antenna>,
+    cells: Grid<Option<Antenna>>,
 }
 
 impl Map {
+    pub fn antennae(&self) -> impl Iterator<Item = (Position, Antenna)> + '_ {
+        self.cells
+            .iter()
+            .filter_map(|(position, cell)| Some((position, (*cell)?)))
+    }
+
     pub fn bounds_check(&self, position: Position) -> bool {
-        position.x >= 0
-            && position.x < self.width as i64
-            && position.y >= 0
-            && position.y < self.height as i64
+        self.cells.contains(position)
     }
 }
 
 impl FromStr for Map {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let lines = string.lines().collect_vec();
-        let height = lines.len() as u64;
-        let width = lines[0].len() as u64;
-
-        let antennae = lines
-            .into_iter()
-            .enumerate()
-            .flat_map(|(y, line)| {
-                line.chars()
-                    .enumerate()
-                    .filter(|(_, char)| *char != '.') // Dot represents empty cell.
-                    .map(move |(x, char)| {
-                        (
-                            Position {
-                                x: x as i64,
-                                y: y as i64,
-                            },
-                            Antenna(char),
-                        )
-                    })
-            })
-            .collect();
-
-        Ok(Self {
-            width,
-            height,
-            antennae,
-        })
+        let cells = Grid::parse_with(string, |char| match char {
+            '.' => Some(None), // Dot represents empty cell.
+            _ if char.is_ascii_alphanumeric() => Some(Some(Antenna(char))),
+            _ => None,
+        })?;
+
+        Ok(Self { cells })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use super::*;
 
     #[test]
@@ -125,10 +65,26 @@ mod tests {
 
         let map: Map = input.parse().unwrap();
 
-        assert_eq!(map.width, 10);
-        assert_eq!(map.height, 10);
-        assert_eq!(map.antennae[&Position { x: 4, y: 3 }], Antenna('a'));
-        assert_eq!(map.antennae[&Position { x: 5, y: 5 }], Antenna('a'));
-        assert_eq!(map.antennae[&Position { x: 8, y: 8 }], Antenna('A'));
+        let antennae: HashMap<_, _> = map.antennae().collect();
+
+        assert_eq!(map.cells.width(), 10);
+        assert_eq!(map.cells.height(), 10);
+        assert_eq!(antennae.len(), 3);
+        assert_eq!(antennae[&Position::new(4, 3)], Antenna('a'));
+        assert_eq!(antennae[&Position::new(5, 5)], Antenna('a'));
+        assert_eq!(antennae[&Position::new(8, 8)], Antenna('A'));
+    }
+
+    #[test]
+    fn parse_map_invalid_antenna() {
+        let input = "...
+.a.
+..%";
+
+        let error = input.parse::<Map>().unwrap_err();
+
+        assert_eq!(error.line, 3);
+        assert_eq!(error.column, 3);
+        assert_eq!(error.text, "%");
     }
 }
@@ -1,10 +1,11 @@
 use std::{
     cmp::Ordering,
-    convert::Infallible,
     fmt::{Display, Formatter},
     str::FromStr,
 };
 
+use common::ParseError;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct File {
     pub id: u64,
@@ -18,6 +19,7 @@ impl File {
     }
 }
 
+#[derive(Clone)]
 pub struct DiskMap {
     pub files: Vec<File>,
     pub size: usize,
@@ -76,7 +78,7 @@ impl DiskMap {
 }
 
 impl FromStr for DiskMap {
-    type Err = Infallible;
+    type Err = ParseError;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
         let mut is_file = true;
@@ -84,8 +86,12 @@ impl FromStr for DiskMap {
         let mut disk_blocks = Vec::with_capacity(string.len() / 2 + 1);
         let mut disk_size = 0;
         let mut file_id = 0u64;
-        for char in string.trim().chars() {
-            let block_size = char.to_digit(10).unwrap() as usize;
+        let leading_whitespace = string.len() - string.trim_start().len();
+        for (idx, char) in string.trim().char_indices() {
+            let block_size = char.to_digit(10).ok_or_else(|| {
+                let offset = leading_whitespace + idx;
+                ParseError::at_offset(string, offset, format!("invalid block size `{char}`"))
+            })? as usize;
 
             if is_file {
                 disk_blocks.push(File {
@@ -1,5 +1,6 @@
 mod disk;
 
+use common::{Answer, ParseError, Solution};
 use disk::*;
 
 fn compact_filesystem_fragmented(disk_map: &mut DiskMap) {
@@ -51,10 +52,16 @@ fn calculate_checksum_fragmented(disk_map: &DiskMap) -> u64 {
         .sum()
 }
 
-pub fn solve_part_1(input: &str) -> u64 {
-    let mut disk_map: DiskMap = input.parse().expect("Failed to parse puzzle input");
+pub fn try_solve_part_1(input: &str) -> Result<u64, ParseError> {
+    let mut disk_map: DiskMap = input.parse()?;
+
     compact_filesystem_fragmented(&mut disk_map);
-    calculate_checksum_fragmented(&disk_map)
+
+    Ok(calculate_checksum_fragmented(&disk_map))
+}
+
+pub fn solve_part_1(input: &str) -> u64 {
+    try_solve_part_1(input).expect("Failed to parse puzzle input")
 }
 
 fn compact_filesystem_defragmented(disk_map: &mut DiskMap) {
@@ -110,8 +117,40 @@ fn calculate_checksum_defragmented(disk_map: &DiskMap) -> u64 {
         .sum()
 }
 
-pub fn solve_part_2(input: &str) -> u64 {
-    let mut disk_map: DiskMap = input.parse().expect("Failed to parse puzzle input");
+pub fn try_solve_part_2(input: &str) -> Result<u64, ParseError> {
+    let mut disk_map: DiskMap = input.parse()?;
+
     compact_filesystem_defragmented(&mut disk_map);
-    calculate_checksum_defragmented(&disk_map)
+
+    Ok(calculate_checksum_defragmented(&disk_map))
+}
+
+pub fn solve_part_2(input: &str) -> u64 {
+    try_solve_part_2(input).expect("Failed to parse puzzle input")
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Disk Fragmenter";
+    const EXAMPLE: &'static str = "2333133121414131402";
+
+    type Input = DiskMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(disk_map: &Self::Input) -> Answer {
+        let mut disk_map = disk_map.clone();
+        compact_filesystem_fragmented(&mut disk_map);
+        calculate_checksum_fragmented(&disk_map).into()
+    }
+
+    fn part_2(disk_map: &Self::Input) -> Answer {
+        let mut disk_map = disk_map.clone();
+        compact_filesystem_defragmented(&mut disk_map);
+        calculate_checksum_defragmented(&disk_map).into()
+    }
 }
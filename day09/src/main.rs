@@ -1,9 +1,12 @@
-use std::fs;
-
+use common::{
+    input::{InputStore, REAL},
+    Solution,
+};
 use day09::*;
 
 fn main() {
-    let contents = fs::read_to_string("day09/input.txt")
+    let contents = InputStore::from_env()
+        .and_then(|store| store.load(Day09::DAY, REAL))
         .expect("Failed to read puzzle input");
 
     println!("Part 1: {}", solve_part_1(&contents));
@@ -15,7 +18,7 @@ fn main() {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "2333133121414131402";
+    const INPUT: &str = Day09::EXAMPLE;
 
     #[test]
     fn test_part_1() {
@@ -30,4 +33,14 @@ mod tests {
 
         assert_eq!(sol, 2858);
     }
+
+    #[test]
+    fn test_parse_surrounding_whitespace() {
+        assert_eq!(solve_part_1(&format!(" {INPUT}\n")), 1928);
+
+        let error = try_solve_part_1(" 23x3").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 4));
+        assert_eq!(error.text, "x3");
+    }
 }
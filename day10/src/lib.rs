@@ -1,5 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
+use common::{Answer, ParseError, Solution};
+use grid::{Direction, Position};
 use map::TopoMap;
 
 mod map;
@@ -8,10 +10,10 @@ fn hiking_trails_scores(map: &TopoMap) -> Vec<usize> {
     let trailheads = map.trailheads();
 
     let mut possible_trails: Vec<_> = trailheads.into_iter().map(|head| (head, head)).collect();
-    let mut visited_peaks = HashMap::<(i64, i64), HashSet<(i64, i64)>>::new();
+    let mut visited_peaks = HashMap::<Position, HashSet<Position>>::new();
 
-    while let Some((head, (x, y))) = possible_trails.pop() {
-        let Some(height_at_pos) = map.get(x, y) else {
+    while let Some((head, position)) = possible_trails.pop() {
+        let Some(height_at_pos) = map.get(position) else {
             continue;
         };
 
@@ -19,16 +21,16 @@ fn hiking_trails_scores(map: &TopoMap) -> Vec<usize> {
             visited_peaks
                 .entry(head)
                 .and_modify(|visited| {
-                    visited.insert((x, y));
+                    visited.insert(position);
                 })
-                .or_insert(HashSet::from([(x, y)]));
+                .or_insert(HashSet::from([position]));
             continue;
         }
 
-        let possible_steps = [(x, y - 1), (x, y + 1), (x - 1, y), (x + 1, y)];
-        for (x, y) in possible_steps {
-            if map.get(x, y) == Some(height_at_pos + 1) {
-                possible_trails.push((head, (x, y)));
+        for direction in Direction::ALL {
+            let next_position = position.step(direction);
+            if map.get(next_position) == Some(height_at_pos + 1) {
+                possible_trails.push((head, next_position));
             }
         }
     }
@@ -39,9 +41,14 @@ fn hiking_trails_scores(map: &TopoMap) -> Vec<usize> {
         .collect()
 }
 
+pub fn try_solve_part_1(input: &str) -> Result<usize, ParseError> {
+    let map: TopoMap = input.parse()?;
+
+    Ok(hiking_trails_scores(&map).into_iter().sum())
+}
+
 pub fn solve_part_1(input: &str) -> usize {
-    let map: TopoMap = input.parse().expect("Failed to parse puzzle input");
-    hiking_trails_scores(&map).into_iter().sum()
+    try_solve_part_1(input).expect("Failed to parse puzzle input")
 }
 
 
@@ -49,10 +56,10 @@ fn hiking_trails_ratings(map: &TopoMap) -> Vec<usize> {
     let trailheads = map.trailheads();
 
     let mut possible_trails: Vec<_> = trailheads.into_iter().map(|head| (head, head)).collect();
-    let mut ratings = HashMap::<(i64, i64), usize>::new();
+    let mut ratings = HashMap::<Position, usize>::new();
 
-    while let Some((head, (x, y))) = possible_trails.pop() {
-        let Some(height_at_pos) = map.get(x, y) else {
+    while let Some((head, position)) = possible_trails.pop() {
+        let Some(height_at_pos) = map.get(position) else {
             continue;
         };
 
@@ -66,10 +73,10 @@ fn hiking_trails_ratings(map: &TopoMap) -> Vec<usize> {
             continue;
         }
 
-        let possible_steps = [(x, y - 1), (x, y + 1), (x - 1, y), (x + 1, y)];
-        for (x, y) in possible_steps {
-            if map.get(x, y) == Some(height_at_pos + 1) {
-                possible_trails.push((head, (x, y)));
+        for direction in Direction::ALL {
+            let next_position = position.step(direction);
+            if map.get(next_position) == Some(height_at_pos + 1) {
+                possible_trails.push((head, next_position));
             }
         }
     }
@@ -79,7 +86,41 @@ fn hiking_trails_ratings(map: &TopoMap) -> Vec<usize> {
         .collect()
 }
 
+pub fn try_solve_part_2(input: &str) -> Result<usize, ParseError> {
+    let map: TopoMap = input.parse()?;
+
+    Ok(hiking_trails_ratings(&map).into_iter().sum())
+}
+
 pub fn solve_part_2(input: &str) -> usize {
-    let map: TopoMap = input.parse().expect("Failed to parse puzzle input");
-    hiking_trails_ratings(&map).into_iter().sum()
+    try_solve_part_2(input).expect("Failed to parse puzzle input")
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Hoof It";
+    const EXAMPLE: &'static str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    type Input = TopoMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(map: &Self::Input) -> Answer {
+        hiking_trails_scores(map).into_iter().sum::<usize>().into()
+    }
+
+    fn part_2(map: &Self::Input) -> Answer {
+        hiking_trails_ratings(map).into_iter().sum::<usize>().into()
+    }
 }
@@ -1,9 +1,12 @@
-use std::fs;
-
+use common::{
+    input::{InputStore, REAL},
+    Solution,
+};
 use day10::*;
 
 fn main() {
-    let contents = fs::read_to_string("day10/input.txt")
+    let contents = InputStore::from_env()
+        .and_then(|store| store.load(Day10::DAY, REAL))
         .expect("Failed to read puzzle input");
 
     println!("Part 1: {}", solve_part_1(&contents));
@@ -15,14 +18,7 @@ fn main() {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "89010123
-78121874
-87430965
-96549874
-45678903
-32019012
-01329801
-10456732";
+    const INPUT: &str = Day10::EXAMPLE;
 
     #[test]
     fn test_part_1() {
@@ -1,10 +1,16 @@
 use std::{collections::HashMap, mem};
 
-fn parse_input(input: &str) -> Vec<u64> {
+use common::{Answer, ParseError, Solution};
+
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
     input
-        .trim()
-        .split(' ')
-        .map(|number| number.parse().expect("Input contains only numbers"))
+        .split_ascii_whitespace()
+        .map(|number| {
+            number.parse().map_err(|_| {
+                let offset = number.as_ptr() as usize - input.as_ptr() as usize;
+                ParseError::at_offset(input, offset, "invalid number")
+            })
+        })
         .collect()
 }
 
@@ -20,7 +26,7 @@ fn blink_once(stones: &mut Vec<u64>) {
         }
 
         let num_digits = stone.to_string().len();
-        if num_digits % 2 == 0 {
+        if num_digits.is_multiple_of(2) {
             let half_len: u32 = (num_digits / 2).try_into().unwrap();
             let ten_to_half_len = 10u64.pow(half_len);
             stones[i] = stone / ten_to_half_len;
@@ -34,16 +40,26 @@ fn blink_once(stones: &mut Vec<u64>) {
     }
 }
 
-pub fn solve_part_1(input: &str) -> usize {
-    let mut stones = parse_input(input);
+fn count_stones_after_blinks_naive(stones: &[u64], num_blinks: usize) -> usize {
+    let mut stones = stones.to_vec();
 
-    for _ in 0..25 {
+    for _ in 0..num_blinks {
         blink_once(&mut stones);
     }
 
     stones.len()
 }
 
+pub fn try_solve_part_1(input: &str) -> Result<usize, ParseError> {
+    let stones = parse_input(input)?;
+
+    Ok(count_stones_after_blinks_naive(&stones, 25))
+}
+
+pub fn solve_part_1(input: &str) -> usize {
+    try_solve_part_1(input).expect("Failed to parse puzzle input")
+}
+
 fn count_stones_after_blinks(stones: &[u64], num_blinks: usize) -> u128 {
     let mut cache: HashMap<u128, u128> = stones.iter().map(|&stone| (stone.into(), 1)).collect();
     for _ in 0..num_blinks {
@@ -63,7 +79,7 @@ fn count_stones_after_blinks(stones: &[u64], num_blinks: usize) -> u128 {
             }
 
             let num_digits = stone.to_string().len();
-            if num_digits % 2 == 0 {
+            if num_digits.is_multiple_of(2) {
                 let half_len: u32 = (num_digits / 2).try_into().unwrap();
                 let ten_to_half_len = 10u128.pow(half_len);
                 update_count_in_cache(stone / ten_to_half_len);
@@ -78,8 +94,34 @@ fn count_stones_after_blinks(stones: &[u64], num_blinks: usize) -> u128 {
     cache.into_values().sum()
 }
 
+pub fn try_solve_part_2(input: &str) -> Result<u128, ParseError> {
+    let stones = parse_input(input)?;
+
+    Ok(count_stones_after_blinks(&stones, 75))
+}
+
 pub fn solve_part_2(input: &str) -> u128 {
-    let stones = parse_input(input);
+    try_solve_part_2(input).expect("Failed to parse puzzle input")
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Plutonian Pebbles";
+    const EXAMPLE: &'static str = "125 17";
+
+    type Input = Vec<u64>;
 
-    count_stones_after_blinks(&stones, 75)
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(stones: &Self::Input) -> Answer {
+        count_stones_after_blinks_naive(stones, 25).into()
+    }
+
+    fn part_2(stones: &Self::Input) -> Answer {
+        count_stones_after_blinks(stones, 75).into()
+    }
 }
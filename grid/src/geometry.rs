@@ -0,0 +1,305 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Location of a cell, with `x` growing to the right and `y` growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Position {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns position one step away in given direction.
+    pub fn step(self, direction: impl Into<Offset>) -> Self {
+        self + direction.into()
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> u64 {
+        (other - self).manhattan_length()
+    }
+
+    pub fn chebyshev_distance(self, other: Self) -> u64 {
+        (other - self).chebyshev_length()
+    }
+}
+
+/// Difference between two positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Offset {
+    pub dx: i64,
+    pub dy: i64,
+}
+
+impl Offset {
+    pub const fn new(dx: i64, dy: i64) -> Self {
+        Self { dx, dy }
+    }
+
+    pub fn manhattan_length(self) -> u64 {
+        self.dx.unsigned_abs() + self.dy.unsigned_abs()
+    }
+
+    pub fn chebyshev_length(self) -> u64 {
+        self.dx.unsigned_abs().max(self.dy.unsigned_abs())
+    }
+}
+
+impl Add<Offset> for Position {
+    type Output = Self;
+
+    fn add(self, rhs: Offset) -> Self::Output {
+        Self::new(self.x + rhs.dx, self.y + rhs.dy)
+    }
+}
+
+impl AddAssign<Offset> for Position {
+    fn add_assign(&mut self, rhs: Offset) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Offset> for Position {
+    type Output = Self;
+
+    fn sub(self, rhs: Offset) -> Self::Output {
+        Self::new(self.x - rhs.dx, self.y - rhs.dy)
+    }
+}
+
+impl SubAssign<Offset> for Position {
+    fn sub_assign(&mut self, rhs: Offset) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub for Position {
+    type Output = Offset;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Offset::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Add for Offset {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.dx + rhs.dx, self.dy + rhs.dy)
+    }
+}
+
+impl Sub for Offset {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.dx - rhs.dx, self.dy - rhs.dy)
+    }
+}
+
+impl Neg for Offset {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.dx, -self.dy)
+    }
+}
+
+impl Mul<i64> for Offset {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self::new(self.dx * rhs, self.dy * rhs)
+    }
+}
+
+/// One of four cardinal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions in clockwise order, starting with `Up`.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn rotate_clockwise(self) -> Self {
+        use Direction::*;
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    pub fn rotate_counter_clockwise(self) -> Self {
+        use Direction::*;
+        match self {
+            Up => Left,
+            Left => Down,
+            Down => Right,
+            Right => Up,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        use Direction::*;
+        match self {
+            Up => Down,
+            Right => Left,
+            Down => Up,
+            Left => Right,
+        }
+    }
+}
+
+impl From<Direction> for Offset {
+    fn from(direction: Direction) -> Self {
+        use Direction::*;
+        match direction {
+            Up => Offset::new(0, -1),
+            Right => Offset::new(1, 0),
+            Down => Offset::new(0, 1),
+            Left => Offset::new(-1, 0),
+        }
+    }
+}
+
+/// One of four cardinal or four diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// All directions in clockwise order, starting with `Up`.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|&direction| direction == self)
+            .unwrap()
+    }
+
+    /// Rotates by 45 degrees clockwise.
+    pub fn rotate_clockwise(self) -> Self {
+        Self::ALL[(self.index() + 1) % 8]
+    }
+
+    /// Rotates by 45 degrees counter-clockwise.
+    pub fn rotate_counter_clockwise(self) -> Self {
+        Self::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+impl From<Direction8> for Offset {
+    fn from(direction: Direction8) -> Self {
+        use Direction8::*;
+        match direction {
+            Up => Offset::new(0, -1),
+            UpRight => Offset::new(1, -1),
+            Right => Offset::new(1, 0),
+            DownRight => Offset::new(1, 1),
+            Down => Offset::new(0, 1),
+            DownLeft => Offset::new(-1, 1),
+            Left => Offset::new(-1, 0),
+            UpLeft => Offset::new(-1, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_arithmetic() {
+        let a = Position::new(1, 2);
+        let b = Position::new(4, -2);
+
+        assert_eq!(b - a, Offset::new(3, -4));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(b - (b - a) * 2, Position::new(-2, 6));
+        assert_eq!(-(b - a), a - b);
+    }
+
+    #[test]
+    fn position_distances() {
+        let a = Position::new(1, 2);
+        let b = Position::new(4, -2);
+
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+    }
+
+    #[test]
+    fn position_step() {
+        let position = Position::new(0, 0);
+
+        assert_eq!(position.step(Direction::Up), Position::new(0, -1));
+        assert_eq!(position.step(Direction8::DownLeft), Position::new(-1, 1));
+    }
+
+    #[test]
+    fn direction_rotations() {
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.rotate_clockwise().rotate_counter_clockwise(),
+                direction
+            );
+            assert_eq!(
+                direction.rotate_clockwise().rotate_clockwise(),
+                direction.opposite()
+            );
+            assert_eq!(Offset::from(direction.opposite()), -Offset::from(direction));
+        }
+
+        for direction in Direction8::ALL {
+            assert_eq!(
+                direction.rotate_clockwise().rotate_counter_clockwise(),
+                direction
+            );
+            assert_eq!(Offset::from(direction.opposite()), -Offset::from(direction));
+        }
+
+        assert_eq!(Direction8::UpLeft.rotate_clockwise(), Direction8::Up);
+    }
+}
@@ -0,0 +1,317 @@
+pub mod geometry;
+mod window;
+
+use std::str::FromStr;
+
+use common::ParseError;
+use itertools::Itertools;
+
+pub use geometry::{Direction, Direction8, Offset, Position};
+pub use window::Window;
+
+/// Rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert!(width > 0, "Grid must have at least one column");
+        assert_eq!(cells.len(), width * height);
+
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses grid of lines of equal length, mapping every character with `parse_cell`.
+    pub fn parse_with(
+        string: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let lines = string.lines().collect_vec();
+        let height = lines.len();
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(ParseError::new(1, 1, "", "grid is empty"));
+        }
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, line) in lines.into_iter().enumerate() {
+            if line.chars().count() != width {
+                return Err(ParseError::new(
+                    y + 1,
+                    1,
+                    line,
+                    format!("expected row of width {width}"),
+                ));
+            }
+
+            for (x, (idx, char)) in line.char_indices().enumerate() {
+                let cell = parse_cell(char).ok_or_else(|| {
+                    ParseError::new(y + 1, x + 1, &line[idx..], format!("invalid cell `{char}`"))
+                })?;
+                cells.push(cell);
+            }
+        }
+
+        Ok(Self::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        (0..self.width as i64).contains(&position.x)
+            && (0..self.height as i64).contains(&position.y)
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.x as usize + position.y as usize * self.width)
+    }
+
+    fn position_of(&self, index: usize) -> Position {
+        Position::new((index % self.width) as i64, (index / self.width) as i64)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        let idx = self.index_of(position)?;
+        self.cells.get(idx)
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        let idx = self.index_of(position)?;
+        self.cells.get_mut(idx)
+    }
+
+    /// Replaces cell at `position`, returning the previous value. Does nothing when out of bounds.
+    pub fn set(&mut self, position: Position, value: T) -> Option<T> {
+        let cell = self.get_mut(position)?;
+        Some(std::mem::replace(cell, value))
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Iterates over all cells together with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| (self.position_of(idx), cell))
+    }
+
+    /// Finds position of the first cell matching `predicate`, row by row.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        let idx = self.cells.iter().position(predicate)?;
+        Some(self.position_of(idx))
+    }
+
+    /// Returns in-bounds horizontal and vertical neighbours of `position`.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| position.step(direction))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// Returns in-bounds horizontal, vertical and diagonal neighbours of `position`.
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| position.step(direction))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// Iterates over cells starting at `start` and moving by `step`, until leaving the grid.
+    pub fn line(&self, start: Position, step: impl Into<Offset>) -> impl Iterator<Item = &T> {
+        let step = step.into();
+        assert_ne!(step, Offset::new(0, 0), "Line must move in some direction");
+
+        std::iter::successors(Some(start), move |&position| Some(position + step))
+            .map_while(|position| self.get(position))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height);
+
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterates over all diagonals going from upper-left to lower-right.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.height as i64)
+            .rev()
+            .map(|y| Position::new(0, y))
+            .chain((1..self.width as i64).map(|x| Position::new(x, 0)));
+
+        starts.map(|start| self.line(start, Direction8::DownRight))
+    }
+
+    /// Iterates over all diagonals going from upper-right to lower-left.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let last_column = self.width as i64 - 1;
+        let starts = (0..self.width as i64)
+            .map(|x| Position::new(x, 0))
+            .chain((1..self.height as i64).map(move |y| Position::new(last_column, y)));
+
+        starts.map(|start| self.line(start, Direction8::DownLeft))
+    }
+
+    /// Iterates over all `width` by `height` windows, row by row.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = Window<'_, T>> {
+        let xs = 0..(self.width + 1).saturating_sub(width);
+        let ys = 0..(self.height + 1).saturating_sub(height);
+
+        ys.cartesian_product(xs).map(move |(y, x)| {
+            let origin = Position::new(x as i64, y as i64);
+            Window::new(self, origin, width, height)
+        })
+    }
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(string, |char| T::try_from(char).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "ABC
+DEF
+GHI";
+
+    fn collect<'a>(cells: impl Iterator<Item = &'a char>) -> String {
+        cells.collect()
+    }
+
+    #[test]
+    fn grid_parse() {
+        let grid: Grid<char> = SOURCE.parse().unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.get(Position::new(1, 2)), Some(&'H'));
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+    }
+
+    #[test]
+    fn grid_parse_with() {
+        let grid = Grid::parse_with("12\n34", |char| char.to_digit(10)).unwrap();
+
+        assert_eq!(grid.cells(), [1, 2, 3, 4]);
+
+        let error = Grid::parse_with("12\n3x", |char| char.to_digit(10)).unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn grid_parse_uneven_rows() {
+        let error = "ABC\nDE\nFGH".parse::<Grid<char>>().unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.text, "DE");
+    }
+
+    #[test]
+    fn grid_parse_empty() {
+        assert!("".parse::<Grid<char>>().is_err());
+        assert!("\n".parse::<Grid<char>>().is_err());
+        assert!("\nAB".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn grid_set() {
+        let mut grid: Grid<char> = SOURCE.parse().unwrap();
+
+        assert_eq!(grid.set(Position::new(0, 1), 'X'), Some('D'));
+        assert_eq!(grid.set(Position::new(-1, 1), 'X'), None);
+        assert_eq!(grid.row(1), ['X', 'E', 'F']);
+    }
+
+    #[test]
+    fn grid_neighbours() {
+        let grid: Grid<char> = SOURCE.parse().unwrap();
+
+        assert_eq!(grid.neighbours_4(Position::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours_4(Position::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours_8(Position::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours_8(Position::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn grid_rows_and_columns() {
+        let grid: Grid<char> = SOURCE.parse().unwrap();
+
+        let rows = grid.rows().map(|row| collect(row.iter())).collect_vec();
+        let columns = grid.columns().map(collect).collect_vec();
+
+        assert_eq!(rows, ["ABC", "DEF", "GHI"]);
+        assert_eq!(columns, ["ADG", "BEH", "CFI"]);
+    }
+
+    #[test]
+    fn grid_diagonals() {
+        let grid: Grid<char> = SOURCE.parse().unwrap();
+
+        let diagonals = grid.diagonals().map(collect).collect_vec();
+        let anti_diagonals = grid.anti_diagonals().map(collect).collect_vec();
+
+        assert_eq!(diagonals, ["G", "DH", "AEI", "BF", "C"]);
+        assert_eq!(anti_diagonals, ["A", "BD", "CEG", "FH", "I"]);
+    }
+
+    #[test]
+    fn grid_windows() {
+        let grid: Grid<char> = SOURCE.parse().unwrap();
+
+        let windows = grid.windows(2, 2).collect_vec();
+
+        assert_eq!(windows.len(), 4);
+        assert_eq!(windows[3].origin(), Position::new(1, 1));
+        assert_eq!(collect(windows[0].cells()), "ABDE");
+        assert_eq!(collect(windows[1].cells()), "BCEF");
+        assert_eq!(collect(windows[2].cells()), "DEGH");
+        assert_eq!(collect(windows[3].cells()), "EFHI");
+    }
+}
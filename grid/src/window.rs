@@ -0,0 +1,64 @@
+use crate::{Grid, Offset, Position};
+
+/// Rectangular part of a [`Grid`], addressed relative to its upper-left corner.
+#[derive(Debug, Clone, Copy)]
+pub struct Window<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Position,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> Window<'a, T> {
+    pub(crate) fn new(grid: &'a Grid<T>, origin: Position, width: usize, height: usize) -> Self {
+        Self {
+            grid,
+            origin,
+            width,
+            height,
+        }
+    }
+
+    /// Position of the upper-left corner within the grid.
+    pub fn origin(&self) -> Position {
+        self.origin
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&'a T> {
+        if !(0..self.width as i64).contains(&position.x)
+            || !(0..self.height as i64).contains(&position.y)
+        {
+            return None;
+        }
+
+        self.grid
+            .get(self.origin + Offset::new(position.x, position.y))
+    }
+
+    /// Iterates over all cells of the window, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.height as i64).flat_map(move |y| {
+            (0..self.width as i64).filter_map(move |x| self.get(Position::new(x, y)))
+        })
+    }
+
+    /// Iterates over the diagonal starting at the upper-left corner.
+    pub fn diagonal(&self) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.width.min(self.height) as i64).filter_map(|i| self.get(Position::new(i, i)))
+    }
+
+    /// Iterates over the diagonal starting at the upper-right corner.
+    pub fn anti_diagonal(&self) -> impl Iterator<Item = &'a T> + '_ {
+        let last_column = self.width as i64 - 1;
+        (0..self.width.min(self.height) as i64)
+            .filter_map(move |i| self.get(Position::new(last_column - i, i)))
+    }
+}
@@ -1,8 +1,54 @@
-pub fn solve_part_1(input: &str) -> usize {
-    todo!()
+mod parse;
+
+use common::{Answer, ParseError, Solution};
+use parse::{parse_input, PuzzleInput};
+
+fn answer_part_1(_puzzle_input: &PuzzleInput) -> u64 {
+    0
+}
+
+pub fn try_solve_part_1(input: &str) -> Result<u64, ParseError> {
+    let puzzle_input = parse_input(input)?;
+
+    Ok(answer_part_1(&puzzle_input))
 }
 
-#[allow(unused)]
-pub fn solve_part_2(input: &str) -> usize {
-    todo!()
+pub fn solve_part_1(input: &str) -> u64 {
+    try_solve_part_1(input).expect("Failed to parse puzzle input")
+}
+
+fn answer_part_2(_puzzle_input: &PuzzleInput) -> u64 {
+    0
+}
+
+pub fn try_solve_part_2(input: &str) -> Result<u64, ParseError> {
+    let puzzle_input = parse_input(input)?;
+
+    Ok(answer_part_2(&puzzle_input))
+}
+
+pub fn solve_part_2(input: &str) -> u64 {
+    try_solve_part_2(input).expect("Failed to parse puzzle input")
+}
+
+pub struct {{project-name | upper_camel_case}};
+
+impl Solution for {{project-name | upper_camel_case}} {
+    const DAY: u8 = {{day}};
+    const TITLE: &'static str = "";
+    const EXAMPLE: &'static str = "";
+
+    type Input = PuzzleInput;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(puzzle_input: &Self::Input) -> Answer {
+        answer_part_1(puzzle_input).into()
+    }
+
+    fn part_2(puzzle_input: &Self::Input) -> Answer {
+        answer_part_2(puzzle_input).into()
+    }
 }
@@ -1,9 +1,12 @@
-use std::fs;
-
+use common::{
+    input::{InputStore, REAL},
+    Solution,
+};
 use {{crate_name}}::*;
 
 fn main() {
-    let contents = fs::read_to_string("{{project-name}}/input.txt")
+    let contents = InputStore::from_env()
+        .and_then(|store| store.load({{project-name | upper_camel_case}}::DAY, REAL))
         .expect("Failed to read puzzle input");
 
     println!("Part 1: {}", solve_part_1(&contents));
@@ -15,9 +18,10 @@ fn main() {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "";
+    const INPUT: &str = {{project-name | upper_camel_case}}::EXAMPLE;
 
     #[test]
+    #[ignore = "the example and its answer are not filled in yet"]
     fn test_part_1() {
         let sol = solve_part_1(INPUT);
 
@@ -25,7 +29,7 @@ mod tests {
     }
 
     #[test]
-    #[ignore]
+    #[ignore = "the example and its answer are not filled in yet"]
     fn test_part_2() {
         let sol = solve_part_2(INPUT);
 
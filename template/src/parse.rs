@@ -0,0 +1,34 @@
+use common::{parse::parse_all, ParseError};
+use nom::{character::complete::*, combinator::*, multi::*, IResult};
+
+pub type PuzzleInput = Vec<Vec<u64>>;
+
+pub fn parse_input(input: &str) -> Result<PuzzleInput, ParseError> {
+    parse_all(input, number_lines)
+}
+
+fn number(input: &str) -> IResult<&str, u64> {
+    map_res(digit1, str::parse)(input)
+}
+
+fn number_line(input: &str) -> IResult<&str, Vec<u64>> {
+    separated_list1(space1, number)(input)
+}
+
+fn number_lines(input: &str) -> IResult<&str, PuzzleInput> {
+    separated_list1(line_ending, number_line)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number() {
+        let input = "12";
+        let (rem, num) = number(input).unwrap();
+
+        assert_eq!(num, 12);
+        assert!(rem.is_empty());
+    }
+}